@@ -1,9 +1,18 @@
 use crate::helpers::use_all;
 
-#[cfg(any(feature = "projects", feature = "tags"))]
-pub mod projects {
-    super::use_all!(pub data);
-}
+#[cfg(any(
+    feature = "projects",
+    feature = "versions",
+    feature = "version-files",
+    feature = "tags"
+))]
+pub mod projects;
+
+#[cfg(any(feature = "projects", feature = "versions", feature = "version-files"))]
+pub mod versions;
+
+#[cfg(feature = "version-files")]
+pub mod version_files;
 
 #[cfg(feature = "users")]
 pub mod users;
@@ -22,7 +22,7 @@ pub struct Promo {
 endpoint! {
     "GET" "updates/{self.project}/forge_updates.json" {
         #[endpoint(skip)]
-        project: String [project.into()]
+        project: String [project.to_string()]
     } -> "ForgeUpdates";
 
     /// ### Forge Updates JSON file
@@ -39,12 +39,12 @@ endpoint! {
     /// ### Errors
     ///
     /// Returns [ModrinthError::NotFound] if the project does not exist.
-    pub fn forge(project: &str) -> ForgeUpdates {
+    pub fn forge(project: &IdOrSlug) -> ForgeUpdates {
         |res| match res {
             Ok(res) => Ok(res.parse()?),
             Err(_) => Err(ModrinthError::NotFound {
                 resource: "project",
-                id: project.into()
+                id: project.to_string()
             })
         }
     }
@@ -0,0 +1,22 @@
+use crate::helpers::use_all;
+
+use_all!(pub data);
+
+#[cfg(feature = "projects")]
+use_all!(pub create);
+#[cfg(feature = "projects")]
+use_all!(pub dependencies);
+#[cfg(feature = "projects")]
+use_all!(pub edit);
+#[cfg(feature = "projects")]
+use_all!(pub follow);
+#[cfg(feature = "projects")]
+use_all!(pub gallery);
+#[cfg(feature = "projects")]
+use_all!(pub get);
+#[cfg(feature = "projects")]
+use_all!(pub icon);
+#[cfg(feature = "projects")]
+use_all!(pub schedule);
+#[cfg(feature = "projects")]
+use_all!(pub search);
@@ -0,0 +1,180 @@
+use super::{DonationUrl, Loader, Project, ProjectSide, ProjectType, RequestedStatus};
+use crate::{
+    UploadFile,
+    prelude::*,
+    versions::{Dependency, VersionType},
+};
+use bon::Builder;
+
+/// A project to create on Modrinth.
+#[derive(Debug, Clone, Builder, Serialize)]
+pub struct ProjectCreate {
+    /// The slug of the project, used for vanity URLs
+    pub slug: Slug,
+    /// The title or name of the project
+    #[builder(into)]
+    pub title: String,
+    /// A short description of the project
+    #[builder(into)]
+    pub description: String,
+    /// A long form description of the project
+    #[builder(into)]
+    pub body: String,
+    /// The project type of the project
+    pub project_type: ProjectType,
+    /// The client side support of the project
+    pub client_side: ProjectSide,
+    /// The server side support of the project
+    pub server_side: ProjectSide,
+    /// A list of the categories that the project has
+    #[builder(default)]
+    pub categories: Vec<String>,
+    /// A list of categories which are searchable but non-primary
+    #[builder(default)]
+    pub additional_categories: Vec<String>,
+    /// The SPDX license ID of the project
+    #[builder(into)]
+    pub license_id: String,
+    /// The URL to the license of the project, if it is not a standard SPDX license
+    #[builder(into)]
+    pub license_url: Option<String>,
+    /// An optional link to where to submit bugs or issues with the project
+    #[builder(into)]
+    pub issues_url: Option<String>,
+    /// An optional link to the source code of the project
+    #[builder(into)]
+    pub source_url: Option<String>,
+    /// An optional link to the project's wiki page or other relevant information
+    #[builder(into)]
+    pub wiki_url: Option<String>,
+    /// An optional invite link to the project's discord
+    #[builder(into)]
+    pub discord_url: Option<String>,
+    /// A list of donation links for the project
+    #[builder(default)]
+    pub donation_urls: Vec<DonationUrl>,
+    /// The status to request once the project has been approved
+    pub requested_status: Option<RequestedStatus>,
+    /// Whether the project should be created as a draft instead of being submitted for review
+    #[builder(default = true)]
+    pub is_draft: bool,
+    /// The icon of the project
+    #[serde(skip)]
+    pub icon: Option<UploadFile>,
+    /// The versions to upload alongside the project
+    #[serde(skip)]
+    #[builder(default)]
+    pub initial_versions: Vec<InitialVersion>,
+}
+
+/// A version to upload alongside a new project.
+#[derive(Debug, Clone, Builder)]
+pub struct InitialVersion {
+    /// The name of this version
+    #[builder(into)]
+    pub name: String,
+    /// The version number, ideally following semantic versioning
+    #[builder(into)]
+    pub version_number: String,
+    /// The changelog for this version
+    #[builder(into)]
+    pub changelog: Option<String>,
+    /// A list of specific versions of projects that this version depends on
+    #[builder(default)]
+    pub dependencies: Vec<Dependency>,
+    /// A list of the game versions this version supports
+    #[builder(default)]
+    pub game_versions: Vec<String>,
+    /// The release channel of this version
+    pub version_type: VersionType,
+    /// The loaders that this version supports
+    #[builder(default)]
+    pub loaders: Vec<Loader>,
+    /// Whether the version is featured or not
+    #[builder(default)]
+    pub featured: bool,
+    /// The files of this version, the first of which is the primary file
+    pub files: Vec<UploadFile>,
+}
+
+impl ProjectCreate {
+    /// Build the multipart body of the request.
+    fn multipart(&self) -> Result<Vec<u8>, ModrinthError> {
+        #[derive(Serialize)]
+        struct Data<'a> {
+            #[serde(flatten)]
+            project: &'a ProjectCreate,
+            initial_versions: Vec<VersionData<'a>>,
+        }
+
+        #[derive(Serialize)]
+        struct VersionData<'a> {
+            name: &'a str,
+            version_number: &'a str,
+            changelog: &'a Option<String>,
+            dependencies: &'a [Dependency],
+            game_versions: &'a [String],
+            version_type: VersionType,
+            loaders: &'a [Loader],
+            featured: bool,
+            file_parts: Vec<String>,
+            primary_file: Option<String>,
+        }
+
+        // files are referenced by the name of their part
+        let part = |version: usize, file: usize| format!("version-{version}-file-{file}");
+
+        let data = Data {
+            project: self,
+            initial_versions: self
+                .initial_versions
+                .iter()
+                .enumerate()
+                .map(|(v, version)| VersionData {
+                    name: &version.name,
+                    version_number: &version.version_number,
+                    changelog: &version.changelog,
+                    dependencies: &version.dependencies,
+                    game_versions: &version.game_versions,
+                    version_type: version.version_type,
+                    loaders: &version.loaders,
+                    featured: version.featured,
+                    file_parts: (0..version.files.len()).map(|f| part(v, f)).collect(),
+                    primary_file: (!version.files.is_empty()).then(|| part(v, 0)),
+                })
+                .collect(),
+        };
+
+        let mut form = Multipart::new().json("data", &data)?;
+        if let Some(icon) = &self.icon {
+            form = form.file("icon", icon);
+        }
+        for (v, version) in self.initial_versions.iter().enumerate() {
+            for (f, file) in version.files.iter().enumerate() {
+                form = form.file(&part(v, f), file);
+            }
+        }
+
+        Ok(form.finish())
+    }
+}
+
+endpoint! {
+    "POST" "v2/project" {
+        #[endpoint(raw)]
+        body: Vec<u8> [project.multipart()?]
+    } -> "Project" [Authenticated] as MULTIPART;
+
+    /// ### Create a project
+    ///
+    /// Create a new project, optionally uploading its icon and initial versions.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/createproject/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The [ProjectCreate] describing the new project
+    pub fn create(project: &ProjectCreate) -> Project
+}
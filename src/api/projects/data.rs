@@ -1,8 +1,152 @@
 use crate::prelude::*;
 
+use_all!(pub loader);
+#[cfg(feature = "projects")]
+use_all!(pub gallery);
+
+/// A project on Modrinth, such as a mod, modpack, resource pack or shader.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, Deserialize)]
+pub struct Project {
+    /// The ID of the project, encoded as a base62 string
+    pub id: ProjectId,
+    /// The slug of a project, used for vanity URLs
+    pub slug: Option<Slug>,
+    /// The title or name of the project
+    pub title: String,
+    /// A short description of the project
+    pub description: String,
+    /// A long form description of the project
+    pub body: String,
+    /// A list of the categories that the project has
+    pub categories: Vec<String>,
+    /// A list of categories which are searchable but non-primary
+    #[serde(default)]
+    pub additional_categories: Vec<String>,
+    /// The client side support of the project
+    pub client_side: ProjectSide,
+    /// The server side support of the project
+    pub server_side: ProjectSide,
+    /// The project type of the project
+    pub project_type: ProjectType,
+    /// The status of the project
+    pub status: ProjectStatus,
+    /// The requested status when submitting for review or scheduling the project for release
+    pub requested_status: Option<RequestedStatus>,
+    /// The monetization status of the project
+    pub monetization_status: Option<MonetizationStatus>,
+    /// The total number of downloads of the project
+    pub downloads: u64,
+    /// The total number of users following the project
+    pub followers: u64,
+    /// The license of the project
+    pub license: ProjectLicense,
+    /// An optional link to where to submit bugs or issues with the project
+    pub issues_url: Option<String>,
+    /// An optional link to the source code of the project
+    pub source_url: Option<String>,
+    /// An optional link to the project's wiki page or other relevant information
+    pub wiki_url: Option<String>,
+    /// An optional invite link to the project's discord
+    pub discord_url: Option<String>,
+    /// A list of donation links for the project
+    #[serde(default)]
+    pub donation_urls: Vec<DonationUrl>,
+    /// The URL of the project's icon
+    pub icon_url: Option<String>,
+    /// The RGB color of the project, automatically generated from the project icon
+    pub color: Option<u32>,
+    /// The ID of the moderation thread associated with this project
+    pub thread_id: Option<ThreadId>,
+    /// The ID of the team that has ownership of this project
+    pub team: TeamId,
+    /// The date the project was published
+    pub published: DateTime<Utc>,
+    /// The date the project was last updated
+    pub updated: DateTime<Utc>,
+    /// The date the project's status was set to an approved status
+    pub approved: Option<DateTime<Utc>>,
+    /// The date the project's status was submitted to moderators for review
+    pub queued: Option<DateTime<Utc>>,
+    /// A list of the version IDs of the project (will never be empty unless `draft` status)
+    pub versions: Vec<VersionId>,
+    /// A list of all of the game versions supported by the project
+    pub game_versions: Vec<String>,
+    /// A list of all of the loaders supported by the project
+    pub loaders: Vec<Loader>,
+    /// A list of images that have been uploaded to the project's gallery
+    #[serde(default)]
+    pub gallery: Vec<GalleryImage>,
+    // ! body_url and moderator_message are deprecated and always null
+}
+
+/// The license of a project.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct ProjectLicense {
+    /// The SPDX license ID of a project
+    pub id: String,
+    /// The long name of a license
+    pub name: String,
+    /// The URL to this license
+    pub url: Option<String>,
+}
+
+/// A donation link for a project.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DonationUrl {
+    /// The ID of the donation platform
+    pub id: String,
+    /// The donation platform this link is to
+    pub platform: String,
+    /// The URL of the donation platform and user
+    pub url: String,
+}
+
+/// The moderation status of a project.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectStatus {
+    Approved,
+    Archived,
+    Rejected,
+    Draft,
+    Unlisted,
+    Processing,
+    Withheld,
+    Scheduled,
+    Private,
+    Unknown,
+}
+
+/// A status that can be requested for a project when it is submitted for review.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestedStatus {
+    Approved,
+    Archived,
+    Unlisted,
+    Private,
+    Draft,
+}
+
+/// Whether a project is eligible for monetization.
+#[cfg(feature = "projects")]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum MonetizationStatus {
+    Monetized,
+    Demonetized,
+    ForceDemonetized,
+}
+
 #[cfg(any(feature = "projects", feature = "tags"))]
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, strum::Display, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
 pub enum ProjectSide {
     /// The project is required to function.
     Required,
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+/// An image that has been uploaded to a project's gallery.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GalleryImage {
+    /// The URL of the gallery image
+    pub url: String,
+    /// Whether the image is featured in the gallery
+    pub featured: bool,
+    /// The title of the gallery image
+    pub title: Option<String>,
+    /// The description of the gallery image
+    pub description: Option<String>,
+    /// The date and time the gallery image was created
+    pub created: DateTime<Utc>,
+    /// The order of the gallery image. Gallery images are sorted by this field and then alphabetically by title.
+    pub ordering: i64,
+}
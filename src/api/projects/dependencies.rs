@@ -0,0 +1,56 @@
+use super::Project;
+use crate::{prelude::*, versions::Version};
+
+/// The projects and versions that a project depends on.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectDependencies {
+    /// The projects that the project depends on
+    pub projects: Vec<Project>,
+    /// The specific versions that the project depends on
+    pub versions: Vec<Version>,
+}
+
+impl ProjectDependencies {
+    /// Get the dependency project that a dependency version belongs to.
+    pub fn project_of(&self, version: &Version) -> Option<&Project> {
+        self.projects.iter().find(|p| p.id == version.project_id)
+    }
+
+    /// Get the dependency versions that belong to a dependency project.
+    pub fn versions_of<'a>(&'a self, project: &'a Project) -> impl Iterator<Item = &'a Version> {
+        self.versions
+            .iter()
+            .filter(move |v| v.project_id == project.id)
+    }
+}
+
+endpoint! {
+    "GET" "v2/project/{self.project}/dependencies" {
+        #[endpoint(skip)]
+        project: String [project.to_string()]
+    } -> "ProjectDependencies";
+
+    /// ### Get all of a project's dependencies
+    ///
+    /// Get the projects and specific versions that any version of a project depends on.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getdependencies/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project does not exist.
+    pub fn dependencies(project: &IdOrSlug) -> ProjectDependencies {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use super::{DonationUrl, ProjectSide, ProjectStatus, RequestedStatus};
+use crate::prelude::*;
+use bon::Builder;
+
+/// Changes to make to a project.
+///
+/// Fields that are not set are left unchanged. Nullable fields take an [Option],
+/// where [None] clears the field on the project.
+#[derive(Debug, Clone, Default, Builder, Serialize)]
+pub struct ProjectEdit {
+    /// The slug of the project, used for vanity URLs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<Slug>,
+    /// The title or name of the project
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A short description of the project
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A long form description of the project
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// A list of the categories that the project has
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+    /// A list of categories which are searchable but non-primary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_categories: Option<Vec<String>>,
+    /// The client side support of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_side: Option<ProjectSide>,
+    /// The server side support of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_side: Option<ProjectSide>,
+    /// The status of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ProjectStatus>,
+    /// The requested status when submitting for review or scheduling the project for release
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_status: Option<Option<RequestedStatus>>,
+    /// An optional link to where to submit bugs or issues with the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues_url: Option<Option<String>>,
+    /// An optional link to the source code of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<Option<String>>,
+    /// An optional link to the project's wiki page or other relevant information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wiki_url: Option<Option<String>>,
+    /// An optional invite link to the project's discord
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discord_url: Option<Option<String>>,
+    /// A list of donation links for the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_urls: Option<Vec<DonationUrl>>,
+    /// The SPDX license ID of the project
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_id: Option<String>,
+    /// The URL to the license of the project, if it is not a standard SPDX license
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_url: Option<Option<String>>,
+    /// The title of the moderators' message for the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation_message: Option<Option<String>>,
+    /// The body of the moderators' message for the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation_message_body: Option<Option<String>>,
+}
+
+/// Changes to make to many projects at once.
+///
+/// Fields that are not set are left unchanged. Nullable fields take an [Option],
+/// where [None] clears the field on every project.
+#[derive(Debug, Clone, Default, Builder, Serialize)]
+pub struct ProjectsEdit {
+    /// Set the categories of the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+    /// Add categories to the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_categories: Option<Vec<String>>,
+    /// Remove categories from the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_categories: Option<Vec<String>>,
+    /// Set the additional categories of the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_categories: Option<Vec<String>>,
+    /// Add additional categories to the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_additional_categories: Option<Vec<String>>,
+    /// Remove additional categories from the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_additional_categories: Option<Vec<String>>,
+    /// Set the donation links of the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_urls: Option<Vec<DonationUrl>>,
+    /// Add donation links to the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_donation_urls: Option<Vec<DonationUrl>>,
+    /// Remove donation links from the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_donation_urls: Option<Vec<DonationUrl>>,
+    /// An optional link to where to submit bugs or issues with the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues_url: Option<Option<String>>,
+    /// An optional link to the source code of the projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<Option<String>>,
+    /// An optional link to the projects' wiki page or other relevant information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wiki_url: Option<Option<String>>,
+    /// An optional invite link to the projects' discord
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discord_url: Option<Option<String>>,
+}
+
+endpoint! {
+    "PATCH" "v2/project/{self.project}" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(raw)]
+        body: Vec<u8> [json_body(edit)?]
+    } -> "()" [Authenticated];
+
+    /// ### Modify a project
+    ///
+    /// Modify an existing project, leaving any unset fields of the [ProjectEdit] unchanged.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/modifyproject/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `edit` - The changes to make to the project
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project does not exist.
+    pub fn edit(project: &IdOrSlug, edit: &ProjectEdit) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "DELETE" "v2/project/{self.project}" {
+        #[endpoint(skip)]
+        project: String [project.to_string()]
+    } -> "()" [Authenticated];
+
+    /// ### Delete a project
+    ///
+    /// Delete a project, along with all of its versions.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/deleteproject/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project does not exist.
+    pub fn delete(project: &IdOrSlug) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "PATCH" "v2/projects" {
+        #[endpoint(query)]
+        ids: DebugFmt<Vec<String>> [
+            projects
+                .into()
+                .into_iter()
+                .map(|p| Into::<ProjectId>::into(p).to_string())
+                .collect::<Vec<_>>()
+                .into()
+        ],
+        #[endpoint(raw)]
+        body: Vec<u8> [json_body(edit)?]
+    } -> "()" [Authenticated];
+
+    /// ### Bulk-edit multiple projects
+    ///
+    /// Apply the same changes to multiple projects at once.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/patchprojects/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `projects` - A [Vec] of IDs of the projects
+    /// - `edit` - The changes to make to every project
+    pub fn edit_many<T: [Into<ProjectId>]>(projects: impl Into<Vec<T>>, edit: &ProjectsEdit) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+endpoint! {
+    "POST" "v2/project/{self.project}/follow" {
+        #[endpoint(skip)]
+        project: String [project.to_string()]
+    } -> "()" [Authenticated];
+
+    /// ### Follow a project
+    ///
+    /// Follow a project as the currently authenticated user.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/followproject/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::AlreadyFollowing] if the project is already being followed,
+    /// or [ModrinthError::NotFound] if the project does not exist.
+    pub fn follow(project: &IdOrSlug) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 400, .. }) => Err(ModrinthError::AlreadyFollowing(project.to_string())),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "DELETE" "v2/project/{self.project}/follow" {
+        #[endpoint(skip)]
+        project: String [project.to_string()]
+    } -> "()" [Authenticated];
+
+    /// ### Unfollow a project
+    ///
+    /// Unfollow a project as the currently authenticated user.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/unfollowproject/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFollowing] if the project is not being followed,
+    /// or [ModrinthError::NotFound] if the project does not exist.
+    pub fn unfollow(project: &IdOrSlug) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 400, .. }) => Err(ModrinthError::NotFollowing(project.to_string())),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
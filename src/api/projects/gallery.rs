@@ -0,0 +1,148 @@
+use crate::prelude::*;
+use bon::Builder;
+
+/// Details of an image in a project's gallery.
+///
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Default, Builder)]
+pub struct GalleryEdit {
+    /// Whether the image is featured in the gallery
+    pub featured: Option<bool>,
+    /// The title of the gallery image
+    #[builder(into)]
+    pub title: Option<String>,
+    /// The description of the gallery image
+    #[builder(into)]
+    pub description: Option<String>,
+    /// The order of the gallery image. Gallery images are sorted by this field and then alphabetically by title.
+    pub ordering: Option<i64>,
+}
+
+endpoint! {
+    "POST" "v2/project/{self.project}/gallery" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(query)]
+        ext: ImageFormat [image.format],
+        #[endpoint(query)]
+        featured: bool [details.featured.unwrap_or_default()],
+        #[endpoint(query)]
+        title: Option<String> [details.title.clone()],
+        #[endpoint(query)]
+        description: Option<String> [details.description.clone()],
+        #[endpoint(query)]
+        ordering: Option<i64> [details.ordering],
+        #[endpoint(raw)]
+        body: Vec<u8> [image.checked("gallery image size", GALLERY_LIMIT)?]
+    } -> "()" [Authenticated] as image.format.content_type();
+
+    /// ### Create a gallery image
+    ///
+    /// Upload a new image to a project's gallery.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/addgalleryimage/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `image` - The gallery [Image]
+    /// - `details` - The details of the gallery image, which is not featured unless specified
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::OutOfRange] if the image is larger than 5MiB,
+    /// or [ModrinthError::NotFound] if the project does not exist.
+    pub fn add_gallery_image(project: &IdOrSlug, image: &Image, details: &GalleryEdit) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "PATCH" "v2/project/{self.project}/gallery" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(query)]
+        url: String [url.into()],
+        #[endpoint(query)]
+        featured: Option<bool> [edit.featured],
+        #[endpoint(query)]
+        title: Option<String> [edit.title.clone()],
+        #[endpoint(query)]
+        description: Option<String> [edit.description.clone()],
+        #[endpoint(query)]
+        ordering: Option<i64> [edit.ordering]
+    } -> "()" [Authenticated];
+
+    /// ### Modify a gallery image
+    ///
+    /// Modify the details of an image in a project's gallery.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/modifygalleryimage/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `url` - The URL of the gallery image
+    /// - `edit` - The changes to make to the gallery image
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project or image does not exist.
+    pub fn edit_gallery_image(project: &IdOrSlug, url: &str, edit: &GalleryEdit) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "gallery image",
+                id: url.into(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "DELETE" "v2/project/{self.project}/gallery" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(query)]
+        url: String [url.into()]
+    } -> "()" [Authenticated];
+
+    /// ### Delete a gallery image
+    ///
+    /// Remove an image from a project's gallery.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/deletegalleryimage/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `url` - The URL of the gallery image
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project or image does not exist.
+    pub fn delete_gallery_image(project: &IdOrSlug, url: &str) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "gallery image",
+                id: url.into(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
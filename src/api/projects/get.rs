@@ -0,0 +1,139 @@
+use super::Project;
+use crate::prelude::*;
+use std::{collections::HashMap, hash::Hash};
+
+endpoint! {
+    "GET" "v2/project/{self.project}" {
+        #[endpoint(skip)]
+        project: String [project.to_string()]
+    } -> "Project";
+
+    /// ### Get a project
+    ///
+    /// Get a project by its slug or ID.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getproject/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project does not exist.
+    pub fn get(project: &IdOrSlug) -> Project {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+/// The response of a [check].
+#[derive(Deserialize)]
+struct Check {
+    id: ProjectId,
+}
+
+endpoint! {
+    "GET" "v2/project/{self.project}/check" {
+        #[endpoint(skip)]
+        project: String [project.to_string()]
+    } -> "Check";
+
+    /// ### Check project slug/ID validity
+    ///
+    /// Check whether a slug or ID belongs to a project, e.g. to see if a slug is taken.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/checkprojectvalidity/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID to check
+    ///
+    /// Returns the ID of the project it belongs to, or [None] if it is free.
+    pub fn check(project: &IdOrSlug) -> Option<ProjectId> {
+        |res| match res {
+            Ok(res) => Ok(Some(res.parse()?.id)),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Ok(None),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "GET" "v2/projects" {
+        #[endpoint(query)]
+        ids: DebugFmt<Vec<String>> [
+            projects.clone()
+                .into()
+                .into_iter()
+                .map(|p| Into::<IdOrSlug>::into(p).to_string())
+                .collect::<Vec<_>>()
+                .into()
+        ]
+    } -> "Vec<Project>";
+
+    /// ### Get multiple projects
+    ///
+    /// Get multiple projects by their slugs or IDs.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getprojects/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `projects` - A [Vec] of slugs or IDs of the projects
+    ///
+    /// Projects that do not exist are mapped to [None].
+    pub fn many<T: [Clone + Hash + Into<IdOrSlug> + PartialEq + Eq]>(projects: impl Clone + Into<Vec<T>>) -> HashMap<T, Option<Project>> {
+        |res| match res {
+            Ok(res) => {
+                let resolved: Vec<Project> = res.parse()?;
+                let mut out = HashMap::new();
+                for project in projects.into() {
+                    let s: IdOrSlug = project.clone().into();
+                    let project_data = resolved.iter().find(|p| s.matches(&p.id, p.slug.as_ref().map(Slug::as_str)));
+                    out.insert(project, project_data.cloned());
+                }
+                Ok(out)
+            },
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "GET" "v2/projects_random" {
+        #[endpoint(query)]
+        count: usize [{
+            if count > 100 {
+                return Err(ModrinthError::OutOfRange {
+                    name: "count",
+                    value: count,
+                    min: 0,
+                    max: 100,
+                });
+            }
+            count
+        }]
+    } -> "Vec<Project>";
+
+    /// ### Get a list of random projects
+    ///
+    /// Get a number of randomly selected projects.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/randomprojects/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `count` - The number of random projects to return, between 0 and 100
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::OutOfRange] if `count` is greater than 100.
+    pub fn random(count: usize) -> Vec<Project>
+}
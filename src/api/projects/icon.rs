@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+endpoint! {
+    "PATCH" "v2/project/{self.project}/icon" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(query)]
+        ext: ImageFormat [icon.format],
+        #[endpoint(raw)]
+        body: Vec<u8> [icon.checked("icon size", ICON_LIMIT)?]
+    } -> "()" [Authenticated] as icon.format.content_type();
+
+    /// ### Change a project's icon
+    ///
+    /// Upload a new icon for a project, replacing the existing one.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/changeprojecticon/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `icon` - The icon [Image]
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::OutOfRange] if the icon is larger than 256KiB,
+    /// or [ModrinthError::NotFound] if the project does not exist.
+    pub fn set_icon(project: &IdOrSlug, icon: &Image) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "DELETE" "v2/project/{self.project}/icon" {
+        #[endpoint(skip)]
+        project: String [project.to_string()]
+    } -> "()" [Authenticated];
+
+    /// ### Delete a project's icon
+    ///
+    /// Remove the icon of a project.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/deleteprojecticon/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project does not exist.
+    pub fn remove_icon(project: &IdOrSlug) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use super::RequestedStatus;
+use crate::prelude::*;
+
+/// The body of a [schedule] request.
+#[derive(Serialize)]
+struct Schedule {
+    time: DateTime<Utc>,
+    requested_status: RequestedStatus,
+}
+
+endpoint! {
+    "POST" "v2/project/{self.project}/schedule" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(raw)]
+        body: Vec<u8> [{
+            if time <= Utc::now() {
+                return Err(ModrinthError::Schedule(time));
+            }
+            json_body(&Schedule { time, requested_status: status })?
+        }]
+    } -> "()" [Authenticated];
+
+    /// ### Schedule a project
+    ///
+    /// Schedule a project to change to the requested status at a given time.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/scheduleproject/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `time` - The time at which the project's status should change
+    /// - `status` - The status the project should change to
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::Schedule] if `time` is not in the future,
+    /// or [ModrinthError::NotFound] if the project does not exist.
+    pub fn schedule(project: &IdOrSlug, time: DateTime<Utc>, status: RequestedStatus) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
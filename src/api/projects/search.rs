@@ -0,0 +1,332 @@
+use super::{Loader, MonetizationStatus, ProjectSide, ProjectType};
+use crate::prelude::*;
+use bon::Builder;
+use std::fmt;
+
+/// A single search facet, used to narrow down the results of a [search].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Facet {
+    /// The project has the given category
+    Category(String),
+    /// The project supports the given [Loader]
+    Loader(Loader),
+    /// The project is of the given [ProjectType]
+    ProjectType(ProjectType),
+    /// The project supports the given game version, e.g. `1.20.1`
+    GameVersion(String),
+    /// The project has the given client side support
+    ClientSide(ProjectSide),
+    /// The project has the given server side support
+    ServerSide(ProjectSide),
+    /// The project is licensed under the given SPDX license ID, e.g. `MIT`
+    License(String),
+}
+
+impl fmt::Display for Facet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Category(category) => write!(f, "categories:{category}"),
+            // loaders are stored as categories by modrinth
+            Self::Loader(loader) => write!(f, "categories:{loader}"),
+            Self::ProjectType(project_type) => write!(f, "project_type:{project_type}"),
+            Self::GameVersion(version) => write!(f, "versions:{version}"),
+            Self::ClientSide(side) => write!(f, "client_side:{side}"),
+            Self::ServerSide(side) => write!(f, "server_side:{side}"),
+            Self::License(license) => write!(f, "license:{license}"),
+        }
+    }
+}
+
+impl From<Loader> for Facet {
+    fn from(value: Loader) -> Self {
+        Self::Loader(value)
+    }
+}
+
+impl From<ProjectType> for Facet {
+    fn from(value: ProjectType) -> Self {
+        Self::ProjectType(value)
+    }
+}
+
+/// A set of [Facet]s to filter a [search] by.
+///
+/// Facets are composed of groups: a project must match **every** group,
+/// and within a group it must match **any** of the facets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Facets(Vec<Vec<Facet>>);
+
+impl Facets {
+    /// Create an empty set of facets that matches every project.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require that a project matches any of the given facets.
+    pub fn any_of<F: Into<Facet>>(mut self, facets: impl IntoIterator<Item = F>) -> Self {
+        let group: Vec<Facet> = facets.into_iter().map(Into::into).collect();
+        if !group.is_empty() {
+            self.0.push(group);
+        }
+        self
+    }
+
+    /// Require that a project matches all of the given facets.
+    pub fn all_of<F: Into<Facet>>(mut self, facets: impl IntoIterator<Item = F>) -> Self {
+        self.0
+            .extend(facets.into_iter().map(|facet| vec![facet.into()]));
+        self
+    }
+
+    /// Whether there are no facets to filter by.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<&Facets> for DebugFmt<Vec<Vec<String>>> {
+    fn from(value: &Facets) -> Self {
+        DebugFmt(
+            value
+                .0
+                .iter()
+                .map(|group| group.iter().map(Facet::to_string).collect())
+                .collect(),
+        )
+    }
+}
+
+/// The sorting method used for a [search].
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchIndex {
+    #[default]
+    Relevance,
+    Downloads,
+    Follows,
+    Newest,
+    Updated,
+}
+
+/// The parameters of a [search].
+#[derive(Debug, Clone, Builder)]
+pub struct SearchParams {
+    /// The query to search for
+    #[builder(into)]
+    pub query: Option<String>,
+    /// The facets to filter the results by
+    pub facets: Option<Facets>,
+    /// The sorting method used for sorting the results
+    #[builder(default)]
+    pub index: SearchIndex,
+    /// The offset into the search, i.e. how many results to skip
+    #[builder(default)]
+    pub offset: usize,
+    /// The number of results returned by the search (at most 100)
+    #[builder(default = 10)]
+    pub limit: usize,
+}
+
+/// A page of results returned by a [search].
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchResults {
+    /// The list of results
+    pub hits: Vec<SearchHit>,
+    /// The number of results that were skipped by the query
+    pub offset: usize,
+    /// The number of results that were returned by the query
+    pub limit: usize,
+    /// The total number of results that match the query
+    pub total_hits: usize,
+}
+
+/// A project returned by a [search].
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchHit {
+    /// The ID of the project
+    pub project_id: ProjectId,
+    /// The slug of a project, used for vanity URLs
+    pub slug: Option<Slug>,
+    /// The title or name of the project
+    pub title: String,
+    /// A short description of the project
+    pub description: String,
+    /// A list of the categories that the project has
+    pub categories: Vec<String>,
+    /// A list of the categories that the project has which are not secondary
+    #[serde(default)]
+    pub display_categories: Vec<String>,
+    /// The client side support of the project
+    pub client_side: ProjectSide,
+    /// The server side support of the project
+    pub server_side: ProjectSide,
+    /// The project type of the project
+    pub project_type: ProjectType,
+    /// The total number of downloads of the project
+    pub downloads: u64,
+    /// The total number of users following the project
+    pub follows: u64,
+    /// The URL of the project's icon
+    pub icon_url: Option<String>,
+    /// The RGB color of the project, automatically generated from the project icon
+    pub color: Option<u32>,
+    /// The ID of the moderation thread associated with this project
+    pub thread_id: Option<ThreadId>,
+    /// The monetization status of the project
+    pub monetization_status: Option<MonetizationStatus>,
+    /// The username of the project's author
+    pub author: String,
+    /// A list of the game versions the project supports
+    pub versions: Vec<String>,
+    /// The date the project was added to search
+    pub date_created: DateTime<Utc>,
+    /// The date the project was last modified
+    pub date_modified: DateTime<Utc>,
+    /// The latest version of the game that the project supports
+    pub latest_version: Option<String>,
+    /// The SPDX license ID of the project
+    pub license: String,
+    /// All gallery images attached to the project
+    #[serde(default)]
+    pub gallery: Vec<String>,
+    /// The featured gallery image of the project
+    pub featured_gallery: Option<String>,
+}
+
+endpoint! {
+    "GET" "v2/search" {
+        #[endpoint(query)]
+        query: Option<String> [params.query.clone()],
+        #[endpoint(query)]
+        facets: Option<DebugFmt<Vec<Vec<String>>>> [
+            params.facets
+                .as_ref()
+                .filter(|facets| !facets.is_empty())
+                .map(Into::into)
+        ],
+        #[endpoint(query)]
+        index: SearchIndex [params.index],
+        #[endpoint(query)]
+        offset: usize [params.offset],
+        #[endpoint(query)]
+        limit: usize [params.limit]
+    } -> "SearchResults";
+
+    /// ### Search projects
+    ///
+    /// Search for projects matching a query, filtered by [Facets].
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/searchprojects/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `params` - The [SearchParams] to search with
+    pub fn search(params: &SearchParams) -> SearchResults
+}
+
+/// Tracks progress through the pages of a [search_all].
+struct SearchPages {
+    params: SearchParams,
+    max: Option<usize>,
+    taken: usize,
+    done: bool,
+}
+
+impl SearchPages {
+    /// The parameters for the next page, if there is one.
+    fn next_params(&self) -> Option<SearchParams> {
+        let remaining = self.max.map(|max| max.saturating_sub(self.taken));
+        if self.done || remaining == Some(0) {
+            return None;
+        }
+
+        let mut params = self.params.clone();
+        if let Some(remaining) = remaining {
+            params.limit = params.limit.min(remaining);
+        }
+        Some(params)
+    }
+
+    /// Advance past a page, returning its hits.
+    fn advance(
+        &mut self,
+        page: Result<SearchResults, ModrinthError>,
+    ) -> Vec<Result<SearchHit, ModrinthError>> {
+        match page {
+            Ok(page) => {
+                let count = page.hits.len();
+                self.params.offset += count;
+                self.taken += count;
+                self.done = count == 0 || self.params.offset >= page.total_hits;
+                page.hits.into_iter().map(Ok).collect()
+            }
+            Err(err) => {
+                self.done = true;
+                vec![Err(err)]
+            }
+        }
+    }
+}
+
+/// ### Search all pages of projects
+///
+/// Lazily walk every page of a [search], issuing follow-up requests with an
+/// increasing offset until all results have been returned or `max` hits
+/// have been yielded. The `limit` of `params` is used as the page size.
+///
+/// ### Arguments
+///
+/// - `params` - The [SearchParams] to start searching from
+/// - `max` - The maximum number of hits to return, if any
+#[cfg(not(feature = "blocking"))]
+pub fn search_all<Auth: AuthState>(
+    modrinth: &Modrinth<Auth>,
+    params: SearchParams,
+    max: Option<usize>,
+) -> impl futures::Stream<Item = Result<SearchHit, ModrinthError>> {
+    use futures::{StreamExt, stream};
+
+    let pages = SearchPages {
+        params,
+        max,
+        taken: 0,
+        done: false,
+    };
+
+    stream::unfold(pages, move |mut pages| async move {
+        let params = pages.next_params()?;
+        let hits = pages.advance(search(modrinth, &params).await);
+        Some((stream::iter(hits), pages))
+    })
+    .flatten()
+}
+
+/// ### Search all pages of projects
+///
+/// Lazily walk every page of a [search], issuing follow-up requests with an
+/// increasing offset until all results have been returned or `max` hits
+/// have been yielded. The `limit` of `params` is used as the page size.
+///
+/// ### Arguments
+///
+/// - `params` - The [SearchParams] to start searching from
+/// - `max` - The maximum number of hits to return, if any
+#[cfg(feature = "blocking")]
+pub fn search_all<Auth: AuthState>(
+    modrinth: &Modrinth<Auth>,
+    params: SearchParams,
+    max: Option<usize>,
+) -> impl Iterator<Item = Result<SearchHit, ModrinthError>> {
+    let mut pages = SearchPages {
+        params,
+        max,
+        taken: 0,
+        done: false,
+    };
+
+    std::iter::from_fn(move || {
+        let params = pages.next_params()?;
+        Some(pages.advance(search(modrinth, &params)))
+    })
+    .flatten()
+}
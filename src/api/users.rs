@@ -1,4 +1,9 @@
 use crate::helpers::use_all;
 
 use_all!(pub data);
+use_all!(pub edit);
 use_all!(pub get);
+use_all!(pub icon);
+
+#[cfg(feature = "projects")]
+use_all!(pub follows);
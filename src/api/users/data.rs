@@ -1,4 +1,5 @@
 use crate::helpers::use_all;
+use crate::UserId;
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
@@ -20,7 +21,7 @@ pub struct User {
     /// Various data relating to the user's payouts status (you can only see your own)
     pub payout_data: Option<UserPayout>,
     /// The user’s ID
-    pub id: String,
+    pub id: UserId,
     /// The user’s avatar url
     pub avatar_url: String,
     /// The time at which the user was created
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 // see: https://github.com/modrinth/code/blob/bff26af4655587ebb619dfde477356cc6ca5bd4b/apps/labrinth/src/models/v3/users.rs#L58
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -13,3 +13,31 @@ pub struct UserPayout {
     /// The user's Venmo handle, if they have one
     pub venmo_handle: Option<String>,
 }
+
+/// Changes to make to a user's payout details.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PayoutEdit {
+    /// The wallet that payouts are sent to
+    pub payout_wallet: PayoutWallet,
+    /// The kind of address that identifies the wallet
+    pub payout_wallet_type: PayoutWalletType,
+    /// The email address, phone number or handle of the wallet
+    pub payout_address: String,
+}
+
+/// A wallet that payouts can be sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayoutWallet {
+    PayPal,
+    Venmo,
+}
+
+/// The kind of address that identifies a [PayoutWallet].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutWalletType {
+    Email,
+    Phone,
+    UserHandle,
+}
@@ -0,0 +1,190 @@
+use super::{PayoutEdit, User};
+use crate::prelude::*;
+use bon::Builder;
+
+/// The longest username Modrinth accepts.
+const USERNAME_LIMIT: usize = 39;
+
+/// The longest bio Modrinth accepts.
+const BIO_LIMIT: usize = 160;
+
+/// Changes to make to a user's profile.
+///
+/// Fields that are not set are left unchanged. Nullable fields take an [Option],
+/// where [None] clears the field on the user.
+#[derive(Debug, Clone, Default, Builder, Serialize)]
+pub struct UserEdit {
+    /// The user's username
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The user's display name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Option<String>>,
+    /// The user's email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<Option<String>>,
+    /// A description of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<Option<String>>,
+    /// The user's payout details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_data: Option<Option<PayoutEdit>>,
+}
+
+impl UserEdit {
+    /// Check the lengths that Modrinth would otherwise reject.
+    fn validate(&self) -> Result<(), ModrinthError> {
+        let lengths = [
+            ("username", self.username.as_deref(), 1, USERNAME_LIMIT),
+            ("bio", self.bio.as_ref().and_then(|b| b.as_deref()), 0, BIO_LIMIT),
+        ];
+
+        for (name, value, min, max) in lengths {
+            let Some(value) = value.map(|v| v.chars().count()) else {
+                continue;
+            };
+            if !(min..=max).contains(&value) {
+                return Err(ModrinthError::OutOfRange {
+                    name,
+                    value,
+                    min,
+                    max,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The body of an error returned by Modrinth.
+#[derive(Deserialize)]
+struct ApiError {
+    description: String,
+}
+
+endpoint! {
+    "PATCH" "v2/user/{self.user}" {
+        #[endpoint(skip)]
+        user: String [user.to_string()],
+        #[endpoint(raw)]
+        body: Vec<u8> [json_body(edit)?]
+    } -> "()" [Authenticated];
+
+    /// Send a [UserEdit] that has already been validated, without fetching the refreshed user.
+    fn patch(user: &UserId, edit: &UserEdit) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 400, content }) => {
+                let description = content
+                    .and_then(|c| serde_json::from_str::<ApiError>(&c).ok())
+                    .map(|e| e.description)
+                    .unwrap_or_default();
+
+                // Modrinth rejects a username that belongs to someone else with
+                // "Invalid Input: Username {username} is taken!"
+                match &edit.username {
+                    Some(username) if description.ends_with(&format!("Username {username} is taken!")) => {
+                        Err(ModrinthError::UsernameTaken(username.clone()))
+                    }
+                    _ => Err(ModrinthError::InvalidInput(description)),
+                }
+            }
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "user",
+                id: user.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "GET" "v2/user/{self.user}" {
+        #[endpoint(skip)]
+        user: String [user.to_string()]
+    } -> "User";
+
+    /// Fetch a user, without treating every failure as [ModrinthError::NotFound] like [get](super::get) does.
+    fn fetch(user: &IdOrSlug<UserId>) -> User {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "user",
+                id: user.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+/// ### Modify a user
+///
+/// Modify a user's profile, leaving any unset fields of the [UserEdit] unchanged.
+///
+/// This endpoint requires authentication via an authorization header.
+///
+/// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/modifyuser/) for more details.
+///
+/// ### Arguments
+///
+/// - `user` - The username or ID of the user
+/// - `edit` - The changes to make to the user
+///
+/// Returns the user after the changes have been made.
+///
+/// ### Errors
+///
+/// Returns [ModrinthError::OutOfRange] if the username or bio is too long,
+/// [ModrinthError::UsernameTaken] if the new username belongs to another user,
+/// [ModrinthError::InvalidInput] if Modrinth rejects any other field,
+/// or [ModrinthError::NotFound] if the user does not exist.
+/// Other failures, including fetching the user after the edit is applied, return the underlying error.
+#[cfg(not(feature = "blocking"))]
+pub async fn edit<Auth: AuthState + Authenticated>(
+    modrinth: &Modrinth<Auth>,
+    user: &IdOrSlug<UserId>,
+    edit: &UserEdit,
+) -> Result<User, ModrinthError> {
+    edit.validate()?;
+    // resolve the ID first, as a change of username would leave the old name dangling
+    let id = fetch(modrinth, user).await?.id;
+    patch(modrinth, &id, edit).await?;
+    fetch(modrinth, &id.into()).await
+}
+
+/// ### Modify a user
+///
+/// Modify a user's profile, leaving any unset fields of the [UserEdit] unchanged.
+///
+/// This endpoint requires authentication via an authorization header.
+///
+/// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/modifyuser/) for more details.
+///
+/// ### Arguments
+///
+/// - `user` - The username or ID of the user
+/// - `edit` - The changes to make to the user
+///
+/// Returns the user after the changes have been made.
+///
+/// ### Errors
+///
+/// Returns [ModrinthError::OutOfRange] if the username or bio is too long,
+/// [ModrinthError::UsernameTaken] if the new username belongs to another user,
+/// [ModrinthError::InvalidInput] if Modrinth rejects any other field,
+/// or [ModrinthError::NotFound] if the user does not exist.
+/// Other failures, including fetching the user after the edit is applied, return the underlying error.
+#[cfg(feature = "blocking")]
+pub fn edit<Auth: AuthState + Authenticated>(
+    modrinth: &Modrinth<Auth>,
+    user: &IdOrSlug<UserId>,
+    edit: &UserEdit,
+) -> Result<User, ModrinthError> {
+    edit.validate()?;
+    // resolve the ID first, as a change of username would leave the old name dangling
+    let id = fetch(modrinth, user)?.id;
+    patch(modrinth, &id, edit)?;
+    fetch(modrinth, &id.into())
+}
@@ -0,0 +1,34 @@
+use crate::{prelude::*, projects::Project};
+
+endpoint! {
+    "GET" "v2/user/{self.user}/follows" {
+        #[endpoint(skip)]
+        user: String [user.to_string()]
+    } -> "Vec<Project>" [Authenticated];
+
+    /// ### Get a user's followed projects
+    ///
+    /// Get the projects that a user is following.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getfollowedprojects/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `user` - The username or ID of the user
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the user does not exist.
+    pub fn follows(user: &IdOrSlug<UserId>) -> Vec<Project> {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "user",
+                id: user.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::{collections::HashMap, hash::Hash};
 endpoint! {
     "GET" "v2/user/{self.user}" {
         #[endpoint(skip)]
-        user: String [user.into()]
+        user: String [user.to_string()]
     } -> "User";
 
     /// ### Get a user
@@ -20,12 +20,12 @@ endpoint! {
     /// ### Errors
     ///
     /// Returns [ModrinthError::NotFound] if the user does not exist.
-    pub fn get(user: &str) -> User {
+    pub fn get(user: &IdOrSlug<UserId>) -> User {
         |res| match res {
             Ok(res) => Ok(res.parse()?),
             Err(_) => Err(ModrinthError::NotFound {
                 resource: "user",
-                id: user.into(),
+                id: user.to_string(),
             })
         }
     }
@@ -55,7 +55,7 @@ endpoint! {
             users.clone()
                 .into()
                 .into_iter()
-                .map(|u| u.into())
+                .map(|u| Into::<IdOrSlug<UserId>>::into(u).to_string())
                 .collect::<Vec<_>>()
                 .into()
         ]
@@ -74,19 +74,15 @@ endpoint! {
     /// ### Errors
     ///
     /// Returns [ModrinthError::NotFound] if any of the users do not exist.
-    pub fn many<T: [Clone + Hash + Into<String> + PartialEq + Eq]>(users: impl Clone + Into<Vec<T>>) -> HashMap<T, Option<User>> {
+    pub fn many<T: [Clone + Hash + Into<IdOrSlug<UserId>> + PartialEq + Eq]>(users: impl Clone + Into<Vec<T>>) -> HashMap<T, Option<User>> {
         |res| match res {
             Ok(res) => {
                 let resolved: Vec<User> = res.parse()?;
                 let mut out = HashMap::new();
                 for user in users.into() {
-                    let s = user.clone().into();
-                    if !resolved.iter().any(|u| u.username == s) {
-                        out.insert(user, None);
-                    } else {
-                        let user_data = resolved.iter().find(|u| u.username == s);
-                        out.insert(user, user_data.cloned());
-                    }
+                    let s: IdOrSlug<UserId> = user.clone().into();
+                    let user_data = resolved.iter().find(|u| s.matches(&u.id, Some(&u.username)));
+                    out.insert(user, user_data.cloned());
                 }
                 Ok(out)
             },
@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+endpoint! {
+    "PATCH" "v2/user/{self.user}/icon" {
+        #[endpoint(skip)]
+        user: String [user.to_string()],
+        #[endpoint(query)]
+        ext: ImageFormat [icon.format],
+        #[endpoint(raw)]
+        body: Vec<u8> [icon.checked("icon size", ICON_LIMIT)?]
+    } -> "()" [Authenticated] as icon.format.content_type();
+
+    /// ### Change a user's avatar
+    ///
+    /// Upload a new avatar for a user, replacing the existing one.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/changeusericon/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `user` - The username or ID of the user
+    /// - `icon` - The avatar [Image], e.g. from [Image::open] or [Image::from_bytes]
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::OutOfRange] if the avatar is larger than 256KiB,
+    /// or [ModrinthError::NotFound] if the user does not exist.
+    pub fn set_icon(user: &IdOrSlug<UserId>, icon: &Image) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "user",
+                id: user.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "DELETE" "v2/user/{self.user}/icon" {
+        #[endpoint(skip)]
+        user: String [user.to_string()]
+    } -> "()" [Authenticated];
+
+    /// ### Remove a user's avatar
+    ///
+    /// Remove the avatar of a user.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/deleteusericon/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `user` - The username or ID of the user
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the user does not exist.
+    pub fn remove_icon(user: &IdOrSlug<UserId>) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "user",
+                id: user.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
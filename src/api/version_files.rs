@@ -0,0 +1,6 @@
+use crate::helpers::use_all;
+
+use_all!(pub data);
+use_all!(pub delete);
+use_all!(pub get);
+use_all!(pub update);
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+/// The algorithm used to hash a version file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum HashAlgorithm {
+    #[default]
+    Sha1,
+    Sha512,
+}
@@ -0,0 +1,41 @@
+use super::HashAlgorithm;
+use crate::prelude::*;
+
+endpoint! {
+    "DELETE" "v2/version_file/{self.hash}" {
+        #[endpoint(skip)]
+        hash: String [hash.into()],
+        #[endpoint(query)]
+        algorithm: HashAlgorithm [algorithm],
+        #[endpoint(query)]
+        version_id: Option<String> [version.map(VersionId::to_string)]
+    } -> "()" [Authenticated];
+
+    /// ### Delete a file from its hash
+    ///
+    /// Delete a single file from a version, leaving the rest of the version intact.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/deletefilefromhash/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `hash` - The hash of the file, as a hex string
+    /// - `algorithm` - The algorithm the hash was made with
+    /// - `version` - The ID of the version the file belongs to, for when the same file is in multiple versions
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if no version has a file with the hash.
+    pub fn delete(hash: &str, algorithm: HashAlgorithm, version: Option<&VersionId>) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "file",
+                id: hash.into(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::HashAlgorithm;
+use crate::{prelude::*, versions::Version};
+use std::{collections::HashMap, hash::Hash};
+
+endpoint! {
+    "GET" "v2/version_file/{self.hash}" {
+        #[endpoint(skip)]
+        hash: String [hash.into()],
+        #[endpoint(query)]
+        algorithm: HashAlgorithm [algorithm]
+    } -> "Version";
+
+    /// ### Get version from hash
+    ///
+    /// Get the version that a file belongs to by the file's hash.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/versionfromhash/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `hash` - The hash of the file, as a hex string
+    /// - `algorithm` - The algorithm the hash was made with
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if no version has a file with the hash.
+    pub fn get(hash: &str, algorithm: HashAlgorithm) -> Version {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "file",
+                id: hash.into(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+/// The body of a [many] request.
+#[derive(Serialize)]
+struct Hashes {
+    hashes: Vec<String>,
+    algorithm: HashAlgorithm,
+}
+
+endpoint! {
+    "POST" "v2/version_files" {
+        #[endpoint(raw)]
+        body: Vec<u8> [
+            json_body(&Hashes {
+                hashes: hashes.clone().into().into_iter().map(|h| h.into()).collect(),
+                algorithm,
+            })?
+        ]
+    } -> "HashMap<String, Version>";
+
+    /// ### Get versions from hashes
+    ///
+    /// Get the versions that multiple files belong to by the files' hashes.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/versionsfromhashes/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `hashes` - A [Vec] of hashes of the files, as hex strings
+    /// - `algorithm` - The algorithm the hashes were made with
+    ///
+    /// Hashes that do not belong to any version are left out of the map.
+    pub fn many<T: [Clone + Hash + Into<String> + PartialEq + Eq]>(hashes: impl Clone + Into<Vec<T>>, algorithm: HashAlgorithm) -> HashMap<T, Version> {
+        |res| match res {
+            Ok(res) => {
+                let resolved: HashMap<String, Version> = res.parse()?;
+                let mut out = HashMap::new();
+                for hash in hashes.into() {
+                    let s: String = hash.clone().into();
+                    if let Some(version) = resolved.get(&s.to_ascii_lowercase()) {
+                        out.insert(hash, version.clone());
+                    }
+                }
+                Ok(out)
+            },
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::HashAlgorithm;
+use crate::{prelude::*, projects::Loader, versions::Version};
+use bon::Builder;
+use std::{collections::HashMap, hash::Hash};
+
+/// Filters for the versions considered by [update] and [update_many].
+#[derive(Debug, Clone, Default, Builder, Serialize)]
+pub struct UpdateFilters {
+    /// Only consider versions that support any of these loaders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loaders: Option<Vec<Loader>>,
+    /// Only consider versions that support any of these game versions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_versions: Option<Vec<String>>,
+}
+
+endpoint! {
+    "POST" "v2/version_file/{self.hash}/update" {
+        #[endpoint(skip)]
+        hash: String [hash.into()],
+        #[endpoint(query)]
+        algorithm: HashAlgorithm [algorithm],
+        #[endpoint(raw)]
+        body: Vec<u8> [json_body(filters)?]
+    } -> "Version";
+
+    /// ### Latest version of a project from a hash, loader(s), and game version(s)
+    ///
+    /// Get the latest version of the project a file belongs to that matches the [UpdateFilters].
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getlatestversionfromhash/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `hash` - The hash of the file, as a hex string
+    /// - `algorithm` - The algorithm the hash was made with
+    /// - `filters` - The filters to apply to the versions
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if no version has a file with the hash,
+    /// or no version of its project matches the filters.
+    pub fn update(hash: &str, algorithm: HashAlgorithm, filters: &UpdateFilters) -> Version {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "file",
+                id: hash.into(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+/// The body of an [update_many] request.
+#[derive(Serialize)]
+struct Updates<'a> {
+    hashes: Vec<String>,
+    algorithm: HashAlgorithm,
+    #[serde(flatten)]
+    filters: &'a UpdateFilters,
+}
+
+endpoint! {
+    "POST" "v2/version_files/update" {
+        #[endpoint(raw)]
+        body: Vec<u8> [
+            json_body(&Updates {
+                hashes: hashes.clone().into().into_iter().map(|h| h.into()).collect(),
+                algorithm,
+                filters,
+            })?
+        ]
+    } -> "HashMap<String, Version>";
+
+    /// ### Latest versions of multiple projects from hashes, loader(s), and game version(s)
+    ///
+    /// Get the latest version of the projects multiple files belong to that match the [UpdateFilters].
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getlatestversionsfromhashes/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `hashes` - A [Vec] of hashes of the files, as hex strings
+    /// - `algorithm` - The algorithm the hashes were made with
+    /// - `filters` - The filters to apply to the versions
+    ///
+    /// Each hash is mapped to the latest matching version of its project.
+    /// Hashes that do not belong to any version, or have no matching version, are left out of the map.
+    pub fn update_many<T: [Clone + Hash + Into<String> + PartialEq + Eq]>(hashes: impl Clone + Into<Vec<T>>, algorithm: HashAlgorithm, filters: &UpdateFilters) -> HashMap<T, Version> {
+        |res| match res {
+            Ok(res) => {
+                let resolved: HashMap<String, Version> = res.parse()?;
+                let mut out = HashMap::new();
+                for hash in hashes.into() {
+                    let s: String = hash.clone().into();
+                    if let Some(version) = resolved.get(&s.to_ascii_lowercase()) {
+                        out.insert(hash, version.clone());
+                    }
+                }
+                Ok(out)
+            },
+            Err(err) => Err(err.into())
+        }
+    }
+}
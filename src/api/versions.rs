@@ -0,0 +1,12 @@
+use crate::helpers::use_all;
+
+use_all!(pub data);
+
+#[cfg(feature = "versions")]
+use_all!(pub create);
+#[cfg(feature = "versions")]
+use_all!(pub edit);
+#[cfg(feature = "versions")]
+use_all!(pub get);
+#[cfg(feature = "versions")]
+use_all!(pub schedule);
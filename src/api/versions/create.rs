@@ -0,0 +1,157 @@
+use super::{Dependency, RequestedVersionStatus, Version, VersionStatus, VersionType};
+use crate::{UploadFile, prelude::*, projects::Loader};
+use bon::Builder;
+
+/// A version to create on Modrinth.
+#[derive(Debug, Clone, Builder, Serialize)]
+pub struct VersionCreate {
+    /// The ID of the project this version is for
+    pub project_id: ProjectId,
+    /// The name of this version
+    #[builder(into)]
+    pub name: String,
+    /// The version number, ideally following semantic versioning
+    #[builder(into)]
+    pub version_number: String,
+    /// The changelog for this version
+    #[builder(into)]
+    pub changelog: Option<String>,
+    /// A list of specific versions of projects that this version depends on
+    #[builder(default)]
+    pub dependencies: Vec<Dependency>,
+    /// A list of the game versions this version supports
+    #[builder(default)]
+    pub game_versions: Vec<String>,
+    /// The release channel of this version
+    pub version_type: VersionType,
+    /// The loaders that this version supports
+    #[builder(default)]
+    pub loaders: Vec<Loader>,
+    /// Whether the version is featured or not
+    #[builder(default)]
+    pub featured: bool,
+    /// The status of this version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<VersionStatus>,
+    /// The requested status of this version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_status: Option<RequestedVersionStatus>,
+    /// The files of this version
+    #[serde(skip)]
+    pub files: Vec<UploadFile>,
+    /// The name of the primary file, otherwise the first file is the primary file
+    #[builder(into)]
+    #[serde(skip)]
+    pub primary_file: Option<String>,
+}
+
+/// Pack files into a multipart body, after the JSON `data` part.
+pub(crate) fn pack_files(
+    data: &impl Serialize,
+    files: &[UploadFile],
+    part: impl Fn(usize) -> String,
+) -> Result<Multipart, ModrinthError> {
+    let mut form = Multipart::new().json("data", data)?;
+    for (index, file) in files.iter().enumerate() {
+        form = form.file(&part(index), file);
+    }
+
+    Ok(form)
+}
+
+impl VersionCreate {
+    /// Build the multipart body of the request.
+    fn multipart(&self) -> Result<Multipart, ModrinthError> {
+        #[derive(Serialize)]
+        struct Data<'a> {
+            #[serde(flatten)]
+            version: &'a VersionCreate,
+            file_parts: Vec<String>,
+            primary_file: Option<String>,
+        }
+
+        // files are referenced by the name of their part
+        let part = |index: usize| format!("file-{index}");
+
+        let primary = match &self.primary_file {
+            Some(name) => Some(
+                self.files
+                    .iter()
+                    .position(|file| &file.name == name)
+                    .ok_or_else(|| ModrinthError::NotFound {
+                        resource: "primary file",
+                        id: name.clone(),
+                    })?,
+            ),
+            None => (!self.files.is_empty()).then_some(0),
+        };
+
+        let data = Data {
+            version: self,
+            file_parts: (0..self.files.len()).map(part).collect(),
+            primary_file: primary.map(part),
+        };
+
+        pack_files(&data, &self.files, part)
+    }
+}
+
+#[derive(rustify_derive::Endpoint)]
+#[endpoint(method = "POST", path = "v2/version", response = "Version")]
+struct CreateRequest;
+
+/// ### Create a version
+///
+/// Create a new version of a project, uploading its files.
+///
+/// The `progress` callback is called as the contents of each file are sent.
+///
+/// This endpoint requires authentication via an authorization header.
+///
+/// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/createversion/) for more details.
+///
+/// ### Arguments
+///
+/// - `version` - The [VersionCreate] describing the new version
+/// - `progress` - Called with the [FileProgress] of each file as it is uploaded
+///
+/// ### Errors
+///
+/// Returns [ModrinthError::NotFound] if the primary file is not one of the version's files.
+#[cfg(not(feature = "blocking"))]
+pub async fn create<'a, Auth: AuthState + Authenticated>(
+    modrinth: &Modrinth<Auth>,
+    version: &'a VersionCreate,
+    progress: impl FnMut(FileProgress<'a>),
+) -> Result<Version, ModrinthError> {
+    let body = upload(modrinth, CreateRequest, version.multipart()?, &version.files, progress).await?;
+    Ok(parse(body)?)
+}
+
+/// ### Create a version
+///
+/// Create a new version of a project, uploading its files.
+///
+/// The `progress` callback is called as the contents of each file are sent.
+///
+/// This endpoint requires authentication via an authorization header.
+///
+/// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/createversion/) for more details.
+///
+/// ### Arguments
+///
+/// - `version` - The [VersionCreate] describing the new version
+/// - `progress` - Called with the [FileProgress] of each file as it is uploaded
+///
+/// ### Errors
+///
+/// Returns [ModrinthError::NotFound] if the primary file is not one of the version's files.
+#[cfg(feature = "blocking")]
+pub fn create<'a, Auth: AuthState + Authenticated>(
+    modrinth: &Modrinth<Auth>,
+    version: &'a VersionCreate,
+    progress: impl FnMut(FileProgress<'a>),
+) -> Result<Version, ModrinthError> {
+    let body = upload(modrinth, CreateRequest, version.multipart()?, &version.files, progress)?;
+    Ok(parse(body)?)
+}
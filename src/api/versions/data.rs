@@ -0,0 +1,142 @@
+use crate::prelude::*;
+use crate::projects::Loader;
+
+/// A version of a project on Modrinth.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Version {
+    /// The ID of the version, encoded as a base62 string
+    pub id: VersionId,
+    /// The ID of the project this version is for
+    pub project_id: ProjectId,
+    /// The ID of the author who published this version
+    pub author_id: UserId,
+    /// The name of this version
+    pub name: String,
+    /// The version number, ideally following semantic versioning
+    pub version_number: String,
+    /// The changelog for this version
+    pub changelog: Option<String>,
+    /// A list of specific versions of projects that this version depends on
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+    /// A list of the game versions this version supports
+    pub game_versions: Vec<String>,
+    /// The release channel of this version
+    pub version_type: VersionType,
+    /// The loaders that this version supports
+    pub loaders: Vec<Loader>,
+    /// Whether the version is featured or not
+    pub featured: bool,
+    /// The status of this version
+    pub status: Option<VersionStatus>,
+    /// The requested status of this version
+    pub requested_status: Option<RequestedVersionStatus>,
+    /// The date this version was published
+    pub date_published: DateTime<Utc>,
+    /// The number of times this version has been downloaded
+    pub downloads: u64,
+    /// A list of files available for download for this version
+    pub files: Vec<VersionFile>,
+    // ! changelog_url is deprecated and always null
+}
+
+/// The release channel of a version.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionType {
+    Alpha,
+    Beta,
+    Release,
+}
+
+/// The visibility status of a version.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionStatus {
+    Listed,
+    Archived,
+    Draft,
+    Unlisted,
+    Scheduled,
+    Unknown,
+}
+
+/// A status that can be requested for a version.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestedVersionStatus {
+    Listed,
+    Archived,
+    Draft,
+    Unlisted,
+}
+
+/// A dependency of a version on another project, version or file.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Dependency {
+    /// The ID of the version that this version depends on
+    pub version_id: Option<VersionId>,
+    /// The ID of the project that this version depends on
+    pub project_id: Option<ProjectId>,
+    /// The file name of the dependency, mostly used for showing external dependencies on modpacks
+    pub file_name: Option<String>,
+    /// The type of dependency that this version has
+    pub dependency_type: DependencyType,
+}
+
+/// How a version depends on another project.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyType {
+    /// The dependency must be installed
+    Required,
+    /// The dependency enhances the version, but is not required
+    Optional,
+    /// The dependency cannot be installed alongside the version
+    Incompatible,
+    /// The dependency is bundled within the version
+    Embedded,
+}
+
+/// A file that belongs to a version.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VersionFile {
+    /// The hashes of the file
+    pub hashes: VersionFileHashes,
+    /// A direct link to the file
+    pub url: String,
+    /// The name of the file
+    pub filename: String,
+    /// Whether this file is the primary one for its version
+    pub primary: bool,
+    /// The size of the file in bytes
+    pub size: u64,
+    /// The type of the additional file, used mainly for adding resource packs to datapacks
+    pub file_type: Option<FileType>,
+}
+
+/// The hashes of a version file.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct VersionFileHashes {
+    /// The SHA-1 hash of the file
+    pub sha1: String,
+    /// The SHA-512 hash of the file
+    pub sha512: String,
+}
+
+other_enum! {
+    /// The type of an additional version file.
+    #[derive(PartialEq, Eq)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum FileType {
+        RequiredResourcePack,
+        OptionalResourcePack,
+        SourcesJar,
+        DevJar,
+        JavadocJar,
+        Signature,
+        Unknown,
+    }
+
+    Other(String)
+}
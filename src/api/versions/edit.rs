@@ -0,0 +1,229 @@
+use super::{
+    Dependency, RequestedVersionStatus, VersionStatus, VersionType, pack_files,
+};
+use crate::{UploadFile, prelude::*, projects::Loader};
+use bon::Builder;
+use std::collections::HashMap;
+
+/// Changes to make to a version.
+///
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Default, Builder, Serialize)]
+pub struct VersionEdit {
+    /// The name of this version
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The version number, ideally following semantic versioning
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_number: Option<String>,
+    /// The changelog for this version
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<String>,
+    /// A list of specific versions of projects that this version depends on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<Dependency>>,
+    /// A list of the game versions this version supports
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_versions: Option<Vec<String>>,
+    /// The release channel of this version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_type: Option<VersionType>,
+    /// The loaders that this version supports
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loaders: Option<Vec<Loader>>,
+    /// Whether the version is featured or not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured: Option<bool>,
+    /// The status of this version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<VersionStatus>,
+    /// The requested status of this version, where [None] clears it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_status: Option<Option<RequestedVersionStatus>>,
+    /// The SHA-1 hash of the file to make the primary file
+    #[builder(into)]
+    #[serde(
+        serialize_with = "primary_file",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub primary_file: Option<String>,
+}
+
+/// Serialize a primary file hash as the `[algorithm, hash]` pair Modrinth expects.
+fn primary_file<S: serde::Serializer>(hash: &Option<String>, serializer: S) -> Result<S::Ok, S::Error> {
+    hash.as_ref().map(|hash| ("sha1", hash)).serialize(serializer)
+}
+
+endpoint! {
+    "PATCH" "v2/version/{self.version}" {
+        #[endpoint(skip)]
+        version: String [version.to_string()],
+        #[endpoint(raw)]
+        body: Vec<u8> [json_body(edit)?]
+    } -> "()" [Authenticated];
+
+    /// ### Modify a version
+    ///
+    /// Modify an existing version, leaving any unset fields of the [VersionEdit] unchanged.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/modifyversion/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `version` - The ID of the version
+    /// - `edit` - The changes to make to the version
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the version does not exist.
+    pub fn edit(version: &VersionId, edit: &VersionEdit) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "version",
+                id: version.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "DELETE" "v2/version/{self.version}" {
+        #[endpoint(skip)]
+        version: String [version.to_string()]
+    } -> "()" [Authenticated];
+
+    /// ### Delete a version
+    ///
+    /// Delete a version, along with all of its files.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/deleteversion/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `version` - The ID of the version
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the version does not exist.
+    pub fn delete(version: &VersionId) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "version",
+                id: version.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+/// The `data` part of an [add_files] request.
+#[derive(Serialize)]
+struct AddFiles {
+    file_types: HashMap<String, ()>,
+}
+
+#[derive(rustify_derive::Endpoint)]
+#[endpoint(method = "POST", path = "v2/version/{self.version}/file", response = "()")]
+struct AddFilesRequest {
+    #[endpoint(skip)]
+    version: String,
+}
+
+impl AddFilesRequest {
+    fn new(version: &VersionId) -> Self {
+        Self {
+            version: version.to_string(),
+        }
+    }
+
+    /// Build the multipart body of the request.
+    fn multipart(files: &[UploadFile]) -> Result<Multipart, ModrinthError> {
+        pack_files(&AddFiles { file_types: HashMap::new() }, files, |index| {
+            format!("file-{index}")
+        })
+    }
+}
+
+/// Map the result of an [add_files] request.
+fn added(version: &VersionId, res: Result<Vec<u8>, ClientError>) -> Result<(), ModrinthError> {
+    match res {
+        Ok(_) => Ok(()),
+        Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+            resource: "version",
+            id: version.to_string(),
+        }),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// ### Add files to version
+///
+/// Upload additional files to an existing version, such as a sources jar.
+///
+/// The `progress` callback is called as the contents of each file are sent.
+///
+/// This endpoint requires authentication via an authorization header.
+///
+/// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/addfilestoversion/) for more details.
+///
+/// ### Arguments
+///
+/// - `version` - The ID of the version
+/// - `files` - The files to add to the version
+/// - `progress` - Called with the [FileProgress] of each file as it is uploaded
+///
+/// ### Errors
+///
+/// Returns [ModrinthError::NotFound] if the version does not exist.
+#[cfg(not(feature = "blocking"))]
+pub async fn add_files<'a, Auth: AuthState + Authenticated>(
+    modrinth: &Modrinth<Auth>,
+    version: &VersionId,
+    files: &'a [UploadFile],
+    progress: impl FnMut(FileProgress<'a>),
+) -> Result<(), ModrinthError> {
+    let form = AddFilesRequest::multipart(files)?;
+    let res = upload(modrinth, AddFilesRequest::new(version), form, files, progress).await;
+    added(version, res)
+}
+
+/// ### Add files to version
+///
+/// Upload additional files to an existing version, such as a sources jar.
+///
+/// The `progress` callback is called as the contents of each file are sent.
+///
+/// This endpoint requires authentication via an authorization header.
+///
+/// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/addfilestoversion/) for more details.
+///
+/// ### Arguments
+///
+/// - `version` - The ID of the version
+/// - `files` - The files to add to the version
+/// - `progress` - Called with the [FileProgress] of each file as it is uploaded
+///
+/// ### Errors
+///
+/// Returns [ModrinthError::NotFound] if the version does not exist.
+#[cfg(feature = "blocking")]
+pub fn add_files<'a, Auth: AuthState + Authenticated>(
+    modrinth: &Modrinth<Auth>,
+    version: &VersionId,
+    files: &'a [UploadFile],
+    progress: impl FnMut(FileProgress<'a>),
+) -> Result<(), ModrinthError> {
+    let form = AddFilesRequest::multipart(files)?;
+    let res = upload(modrinth, AddFilesRequest::new(version), form, files, progress);
+    added(version, res)
+}
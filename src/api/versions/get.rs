@@ -0,0 +1,164 @@
+use super::Version;
+use crate::{prelude::*, projects::Loader};
+use bon::Builder;
+use std::{collections::HashMap, hash::Hash};
+
+/// Filters for the versions returned by [list].
+#[derive(Debug, Clone, Default, Builder)]
+pub struct VersionFilters {
+    /// Only return versions that support any of these loaders
+    pub loaders: Option<Vec<Loader>>,
+    /// Only return versions that support any of these game versions
+    pub game_versions: Option<Vec<String>>,
+    /// Only return versions that are, or are not, featured
+    pub featured: Option<bool>,
+}
+
+endpoint! {
+    "GET" "v2/project/{self.project}/version" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(query)]
+        loaders: Option<DebugFmt<Vec<String>>> [
+            filters.loaders
+                .as_ref()
+                .map(|loaders| loaders.iter().map(Loader::to_string).collect::<Vec<_>>().into())
+        ],
+        #[endpoint(query)]
+        game_versions: Option<DebugFmt<Vec<String>>> [filters.game_versions.clone().map(Into::into)],
+        #[endpoint(query)]
+        featured: Option<bool> [filters.featured]
+    } -> "Vec<Version>";
+
+    /// ### List a project's versions
+    ///
+    /// Get the versions of a project, optionally filtered by [VersionFilters].
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getprojectversions/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `filters` - The filters to apply to the versions
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project does not exist.
+    pub fn list(project: &IdOrSlug, filters: &VersionFilters) -> Vec<Version> {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "project",
+                id: project.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "GET" "v2/version/{self.version}" {
+        #[endpoint(skip)]
+        version: String [version.to_string()]
+    } -> "Version";
+
+    /// ### Get a version
+    ///
+    /// Get a version by its ID.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getversion/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `version` - The ID of the version
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the version does not exist.
+    pub fn get(version: &VersionId) -> Version {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "version",
+                id: version.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "GET" "v2/project/{self.project}/version/{self.version}" {
+        #[endpoint(skip)]
+        project: String [project.to_string()],
+        #[endpoint(skip)]
+        version: String [version.to_string()]
+    } -> "Version";
+
+    /// ### Get a version given a version number or ID
+    ///
+    /// Get a version of a project by its version number or ID.
+    /// If multiple versions share a version number, the oldest one is returned.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getversionfromidornumber/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `project` - The slug or ID of the project
+    /// - `version` - The version number or ID of the version
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::NotFound] if the project or version does not exist.
+    pub fn by_number(project: &IdOrSlug, version: &str) -> Version {
+        |res| match res {
+            Ok(res) => Ok(res.parse()?),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "version",
+                id: format!("{project}/{version}"),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+endpoint! {
+    "GET" "v2/versions" {
+        #[endpoint(query)]
+        ids: DebugFmt<Vec<String>> [
+            versions.clone()
+                .into()
+                .into_iter()
+                .map(|v| Into::<VersionId>::into(v).to_string())
+                .collect::<Vec<_>>()
+                .into()
+        ]
+    } -> "Vec<Version>";
+
+    /// ### Get multiple versions
+    ///
+    /// Get multiple versions by their IDs.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/getversions/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `versions` - A [Vec] of IDs of the versions
+    ///
+    /// Versions that do not exist are mapped to [None].
+    pub fn many<T: [Clone + Hash + Into<VersionId> + PartialEq + Eq]>(versions: impl Clone + Into<Vec<T>>) -> HashMap<T, Option<Version>> {
+        |res| match res {
+            Ok(res) => {
+                let resolved: Vec<Version> = res.parse()?;
+                let mut out = HashMap::new();
+                for version in versions.into() {
+                    let s = version.clone().into();
+                    let version_data = resolved.iter().find(|v| v.id == s);
+                    out.insert(version, version_data.cloned());
+                }
+                Ok(out)
+            },
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use super::RequestedVersionStatus;
+use crate::prelude::*;
+
+/// The body of a [schedule] request.
+#[derive(Serialize)]
+struct Schedule {
+    time: DateTime<Utc>,
+    requested_status: RequestedVersionStatus,
+}
+
+endpoint! {
+    "POST" "v2/version/{self.version}/schedule" {
+        #[endpoint(skip)]
+        version: String [version.to_string()],
+        #[endpoint(raw)]
+        body: Vec<u8> [{
+            if time <= Utc::now() {
+                return Err(ModrinthError::Schedule(time));
+            }
+            json_body(&Schedule { time, requested_status: status })?
+        }]
+    } -> "()" [Authenticated];
+
+    /// ### Schedule a version
+    ///
+    /// Schedule a version to change to the requested status at a given time.
+    ///
+    /// This endpoint requires authentication via an authorization header.
+    ///
+    /// See the [Modrinth API docs](https://docs.modrinth.com/api/operations/scheduleversion/) for more details.
+    ///
+    /// ### Arguments
+    ///
+    /// - `version` - The ID of the version
+    /// - `time` - The time at which the version's status should change
+    /// - `status` - The status the version should change to
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::Schedule] if `time` is not in the future,
+    /// or [ModrinthError::NotFound] if the version does not exist.
+    pub fn schedule(version: &VersionId, time: DateTime<Utc>, status: RequestedVersionStatus) -> () {
+        |res| match res {
+            Ok(_) => Ok(()),
+            Err(ClientError::ServerResponseError { code: 404, .. }) => Err(ModrinthError::NotFound {
+                resource: "version",
+                id: version.to_string(),
+            }),
+            Err(err) => Err(err.into())
+        }
+    }
+}
@@ -32,7 +32,11 @@ impl AuthState for Unauthenticated {
 }
 
 /// The client is [Authenticated] using a [Personal Access Token](https://modrinth.com/settings/pats).
-pub struct Pat(pub(crate) String, pub(crate) DateTime<Utc>);
+pub struct Pat(
+    pub(crate) String,
+    // todo: used once expiration is checked
+    #[allow(dead_code)] pub(crate) DateTime<Utc>,
+);
 impl Authenticated for Pat {}
 
 fn header_string(
@@ -40,7 +44,7 @@ fn header_string(
     url: String,
     str: &str,
 ) -> Result<HeaderValue, ClientError> {
-    HeaderValue::from_str(&str).map_err(|source| ClientError::RequestBuildError {
+    HeaderValue::from_str(str).map_err(|source| ClientError::RequestBuildError {
         source: source.into(),
         method,
         url,
@@ -64,7 +68,10 @@ impl AuthState for Pat {
 }
 
 /// Insert authentication information into Rustify clients.
-pub struct AuthMiddleware<'a, Auth>(pub(crate) &'a Modrinth<Auth>)
+///
+/// Requests with a body are sent with the given content type,
+/// falling back to JSON which is the only body type Rustify produces.
+pub struct AuthMiddleware<'a, Auth>(pub(crate) &'a Modrinth<Auth>, pub(crate) Option<&'a str>)
 where
     Auth: AuthState;
 
@@ -74,6 +81,18 @@ impl<Auth: AuthState> rustify::MiddleWare for AuthMiddleware<'_, Auth> {
         endpoint: &E,
         req: &mut Request<Vec<u8>>,
     ) -> Result<(), ClientError> {
+        let has_body = !req.body().is_empty();
+        #[allow(unused_mut)]
+        let mut content_type = self.1.unwrap_or("application/json").to_string();
+
+        // multipart bodies declare the boundary they start with
+        #[cfg(any(feature = "projects", feature = "versions"))]
+        if content_type == crate::helpers::MULTIPART
+            && let Some(boundary) = crate::helpers::boundary_of(req.body())
+        {
+            content_type = format!("{content_type}; boundary={boundary}");
+        }
+
         let headers = req.headers_mut();
         let method = endpoint.method();
         let url = endpoint.url(&self.0.client.base)?.to_string();
@@ -84,6 +103,14 @@ impl<Auth: AuthState> rustify::MiddleWare for AuthMiddleware<'_, Auth> {
             header_string(method.clone(), url.clone(), &self.0.user_agent)?,
         );
 
+        // apply content type
+        if has_body {
+            headers.insert(
+                "Content-Type",
+                header_string(method.clone(), url.clone(), &content_type)?,
+            );
+        }
+
         // apply authorization header
         self.0.auth.auth(method, url, headers)
     }
@@ -1,6 +1,20 @@
 use_all!(pub(crate) debug_fmt);
 use_all!(pub(crate) endpoint);
+#[cfg(feature = "hashing")]
+use_all!(pub hashing);
+use_all!(pub id);
+use_all!(pub image);
+#[cfg(any(
+    feature = "projects",
+    feature = "versions",
+    feature = "version-files",
+    feature = "users"
+))]
+use_all!(pub(crate) json_body);
+use_all!(pub multipart);
 use_all!(pub(crate) other_enum);
+#[cfg(feature = "versions")]
+use_all!(pub upload);
 use_all!(pub(crate) vec_enum);
 
 /// A macro to use all items from a module.
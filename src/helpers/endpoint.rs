@@ -12,6 +12,7 @@ macro_rules! endpoint {
     // build the body of an endpoint function
     (@body
         $modrinth:expr; $($res:ident;)? $response:literal;
+        content_type: $($content_type:expr)?;
         code: $([$($code:tt)*])?
         $method:literal, $path:literal: $(
             [$(
@@ -30,7 +31,8 @@ macro_rules! endpoint {
                     $end_arg: $end_ty
                 ),*
             })?;
-            let middleware = $crate::client::AuthMiddleware($modrinth);
+            let content_type: Option<&str> = None $(.or(Some($content_type)))?;
+            let middleware = $crate::client::AuthMiddleware($modrinth, content_type);
             let endpoint = Request $({$($end_arg: $($end_val)*),*})?
                 .with_middleware(&middleware);
             #[cfg(not(feature = "blocking"))]
@@ -49,7 +51,7 @@ macro_rules! endpoint {
                 $(#[$field_meta:meta])*
                 $end_arg:ident: $end_ty:ty [$($end_val:tt)*]
             ),* $(,)?
-        })? -> $response:literal $([$auth:ident])?;
+        })? -> $response:literal $([$auth:ident])? $(as $content_type:expr)?;
         $(#[$fn_meta:meta])*
         $vis:vis fn $name:ident$(<
             $($lifetime:lifetime),* $(,)?
@@ -74,6 +76,7 @@ macro_rules! endpoint {
         ) -> Result<$return_ty, $crate::ModrinthError> {
             endpoint!(@body
                 modrinth; $($res;)? $response;
+                content_type: $($content_type)?;
                 code: $([$($code)*])?
                 $method, $path: $(
                     [$(
@@ -98,6 +101,7 @@ macro_rules! endpoint {
         ) -> Result<$return_ty, $crate::ModrinthError> {
             endpoint!(@body
                 modrinth; $($res;)? $response;
+                content_type: $($content_type)?;
                 code: $([$($code)*])?
                 $method, $path: $(
                     [$(
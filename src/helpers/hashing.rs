@@ -0,0 +1,193 @@
+use futures::{AsyncRead, AsyncReadExt};
+use sha1::{Digest, Sha1};
+use sha2::Sha512;
+use std::{
+    io::{self, Read},
+    path::Path,
+};
+
+/// The size of the buffer used when streaming a file through the hashers.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// The SHA-1 and SHA-512 hashes of a file, as lowercase hex strings.
+///
+/// These are the hashes Modrinth uses to identify version files.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileHashes {
+    /// The SHA-1 hash of the file
+    pub sha1: String,
+    /// The SHA-512 hash of the file
+    pub sha512: String,
+}
+
+/// Both hashers, fed in a single pass over the data.
+#[derive(Default)]
+struct Hasher {
+    sha1: Sha1,
+    sha512: Sha512,
+}
+
+impl Hasher {
+    fn update(&mut self, bytes: &[u8]) {
+        self.sha1.update(bytes);
+        self.sha512.update(bytes);
+    }
+
+    fn finish(self) -> FileHashes {
+        FileHashes {
+            sha1: hex::encode(self.sha1.finalize()),
+            sha512: hex::encode(self.sha512.finalize()),
+        }
+    }
+}
+
+impl FileHashes {
+    /// Hash some bytes that are already in memory.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut hasher = Hasher::default();
+        hasher.update(bytes);
+        hasher.finish()
+    }
+
+    /// Hash everything read from a reader, without buffering all of it in memory.
+    pub fn from_reader(mut reader: impl Read) -> io::Result<Self> {
+        let mut hasher = Hasher::default();
+        let mut buf = vec![0; BUFFER_SIZE];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => return Ok(hasher.finish()),
+                Ok(n) => hasher.update(&buf[..n]),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Hash everything read from an async reader, without buffering all of it in memory.
+    pub async fn from_async_reader(mut reader: impl AsyncRead + Unpin) -> io::Result<Self> {
+        let mut hasher = Hasher::default();
+        let mut buf = vec![0; BUFFER_SIZE];
+        loop {
+            match reader.read(&mut buf).await {
+                Ok(0) => return Ok(hasher.finish()),
+                Ok(n) => hasher.update(&buf[..n]),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Hash a file on disk.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_reader(std::fs::File::open(path)?)
+    }
+
+    /// Get the hash made with an algorithm, ready to pass to the version file endpoints.
+    #[cfg(feature = "version-files")]
+    pub fn get(&self, algorithm: crate::version_files::HashAlgorithm) -> &str {
+        use crate::version_files::HashAlgorithm;
+
+        match algorithm {
+            HashAlgorithm::Sha1 => &self.sha1,
+            HashAlgorithm::Sha512 => &self.sha512,
+        }
+    }
+}
+
+#[cfg(any(feature = "projects", feature = "versions", feature = "version-files"))]
+impl FileHashes {
+    /// Check whether these hashes match the hashes Modrinth declares for a file.
+    pub fn matches(&self, hashes: &crate::versions::VersionFileHashes) -> bool {
+        self.sha1.eq_ignore_ascii_case(&hashes.sha1)
+            && self.sha512.eq_ignore_ascii_case(&hashes.sha512)
+    }
+
+    /// Find the file of a version that these hashes belong to, e.g. to verify a download.
+    ///
+    /// Returns [None] if no file of the version has these hashes.
+    pub fn verify<'a>(
+        &self,
+        version: &'a crate::versions::Version,
+    ) -> Option<&'a crate::versions::VersionFile> {
+        version.files.iter().find(|file| self.matches(&file.hashes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABC_SHA1: &str = "a9993e364706816aba3e25717850c26c9cd0d89d";
+    const ABC_SHA512: &str = "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f";
+
+    #[test]
+    fn known_vectors() {
+        let hashes = FileHashes::from_bytes(b"abc");
+        assert_eq!(hashes.sha1, ABC_SHA1);
+        assert_eq!(hashes.sha512, ABC_SHA512);
+
+        let empty = FileHashes::from_bytes(b"");
+        assert_eq!(empty.sha1, "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+    }
+
+    #[test]
+    fn readers_agree_with_bytes() {
+        // spans several buffers, ending partway through one
+        let bytes: Vec<u8> = (0..BUFFER_SIZE * 2 + 123).map(|i| i as u8).collect();
+        let expected = FileHashes::from_bytes(&bytes);
+
+        assert_eq!(FileHashes::from_reader(bytes.as_slice()).unwrap(), expected);
+        let from_async =
+            futures::executor::block_on(FileHashes::from_async_reader(futures::io::Cursor::new(&bytes)));
+        assert_eq!(from_async.unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(any(feature = "projects", feature = "versions", feature = "version-files"))]
+    fn matches_ignore_hex_case() {
+        use crate::versions::{Version, VersionFileHashes};
+
+        let hashes = FileHashes::from_bytes(b"abc");
+        let upper = VersionFileHashes {
+            sha1: ABC_SHA1.to_uppercase(),
+            sha512: ABC_SHA512.to_uppercase(),
+        };
+        assert!(hashes.matches(&upper));
+        assert!(!FileHashes::from_bytes(b"abd").matches(&upper));
+
+        let file = |name: &str, sha1: &str, sha512: &str| {
+            serde_json::json!({
+                "hashes": { "sha1": sha1, "sha512": sha512 },
+                "url": format!("https://cdn.modrinth.com/{name}"),
+                "filename": name,
+                "primary": false,
+                "size": 3,
+                "file_type": null,
+            })
+        };
+        let version: Version = serde_json::from_value(serde_json::json!({
+            "id": "IIJJKKLL",
+            "project_id": "AANobbMI",
+            "author_id": "TEZXhE2U",
+            "name": "Test",
+            "version_number": "1.0.0",
+            "changelog": null,
+            "game_versions": [],
+            "version_type": "release",
+            "loaders": [],
+            "featured": false,
+            "status": null,
+            "requested_status": null,
+            "date_published": "2024-01-01T00:00:00Z",
+            "downloads": 0,
+            "files": [
+                file("other.jar", &"0".repeat(40), &"0".repeat(128)),
+                file("abc.jar", &upper.sha1, &upper.sha512),
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(hashes.verify(&version).unwrap().filename, "abc.jar");
+        assert!(FileHashes::from_bytes(b"abd").verify(&version).is_none());
+    }
+}
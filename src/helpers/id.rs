@@ -0,0 +1,398 @@
+use crate::ModrinthError;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{fmt, str::FromStr};
+
+/// The base62 alphabet used to encode Modrinth IDs.
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The number of base62 digits in a Modrinth ID.
+///
+/// Modrinth generates IDs in the range `62^7..62^8`, so they always have exactly 8 digits.
+const ID_LENGTH: usize = 8;
+
+/// Decode a base62 string into its numeric value, if it is valid.
+fn decode(id: &str) -> Option<u64> {
+    // a u64 never takes more than 11 base62 digits
+    if id.is_empty() || id.len() > 11 {
+        return None;
+    }
+
+    id.bytes().try_fold(0u64, |value, c| {
+        let digit = BASE62.iter().position(|&d| d == c)? as u64;
+        value.checked_mul(62)?.checked_add(digit)
+    })
+}
+
+/// Encode a numeric value as a base62 string.
+fn encode(mut value: u64) -> String {
+    let mut id = Vec::new();
+    loop {
+        id.push(BASE62[(value % 62) as usize]);
+        value /= 62;
+        if value == 0 {
+            break;
+        }
+    }
+    id.reverse();
+    String::from_utf8(id).expect("base62 digits are ascii")
+}
+
+/// Check whether a string has the shape of a Modrinth ID.
+fn is_id(id: &str) -> bool {
+    id.len() == ID_LENGTH && decode(id).is_some()
+}
+
+/// Create a base62 ID newtype.
+macro_rules! ids {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident($kind:literal)
+    ),* $(,)?) => {$(
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Get the base62 representation of this ID.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Get the numeric value of this ID.
+            pub fn value(&self) -> u64 {
+                decode(&self.0).expect("IDs are validated on creation")
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ModrinthError;
+
+            fn from_str(id: &str) -> Result<Self, Self::Err> {
+                match is_id(id) {
+                    true => Ok(Self(id.into())),
+                    false => Err(ModrinthError::InvalidId {
+                        kind: $kind,
+                        id: id.into(),
+                    }),
+                }
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = ModrinthError;
+
+            fn try_from(id: &str) -> Result<Self, Self::Error> {
+                id.parse()
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ModrinthError;
+
+            fn try_from(id: String) -> Result<Self, Self::Error> {
+                id.parse()
+            }
+        }
+
+        impl TryFrom<u64> for $name {
+            type Error = ModrinthError;
+
+            fn try_from(value: u64) -> Result<Self, Self::Error> {
+                encode(value).parse()
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                // trust Modrinth with the length of its own IDs, only parsing user input strictly
+                let id = String::deserialize(deserializer)?;
+                match decode(&id) {
+                    Some(_) => Ok(Self(id)),
+                    None => Err(serde::de::Error::custom(ModrinthError::InvalidId {
+                        kind: $kind,
+                        id,
+                    })),
+                }
+            }
+        }
+    )*};
+}
+
+ids! {
+    /// The base62 ID of a project.
+    ProjectId("project"),
+    /// The base62 ID of a version.
+    VersionId("version"),
+    /// The base62 ID of a user.
+    UserId("user"),
+    /// The base62 ID of a team.
+    TeamId("team"),
+    /// The base62 ID of a thread.
+    ThreadId("thread"),
+    /// The base62 ID of a notification.
+    NotificationId("notification"),
+    /// The base62 ID of a report.
+    ReportId("report"),
+}
+
+/// A human-readable name used in place of an ID, such as a project slug or a username.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Slug(String);
+
+impl Slug {
+    /// Get this slug as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Slug {
+    type Err = ModrinthError;
+
+    fn from_str(slug: &str) -> Result<Self, Self::Err> {
+        let valid = (1..=64).contains(&slug.chars().count())
+            && slug
+                .chars()
+                .all(|c| c.is_alphanumeric() || "_!@$()`.+,\"-'".contains(c));
+
+        match valid {
+            true => Ok(Self(slug.into())),
+            false => Err(ModrinthError::InvalidSlug(slug.into())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Slug {
+    type Error = ModrinthError;
+
+    fn try_from(slug: &str) -> Result<Self, Self::Error> {
+        slug.parse()
+    }
+}
+
+impl TryFrom<String> for Slug {
+    type Error = ModrinthError;
+
+    fn try_from(slug: String) -> Result<Self, Self::Error> {
+        slug.parse()
+    }
+}
+
+impl AsRef<str> for Slug {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Slug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Slug {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Either an ID or a [Slug], for endpoints that accept both.
+///
+/// IDs and slugs can't be told apart from their text alone, so parsing a string always
+/// gives a [IdOrSlug::Slug], which Modrinth resolves as either. Use [IdOrSlug::id] or
+/// convert from an ID type to refer to something by its ID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IdOrSlug<Id = ProjectId> {
+    /// A base62 ID
+    Id(Id),
+    /// A human-readable slug or username
+    Slug(Slug),
+}
+
+impl<Id: FromStr<Err = ModrinthError>> IdOrSlug<Id> {
+    /// Refer to something by its ID.
+    pub fn id(id: &str) -> Result<Self, ModrinthError> {
+        Ok(Self::Id(id.parse()?))
+    }
+
+    /// Refer to something by its slug or username.
+    pub fn slug(slug: &str) -> Result<Self, ModrinthError> {
+        Ok(Self::Slug(slug.parse()?))
+    }
+}
+
+impl<Id: AsRef<str>> IdOrSlug<Id> {
+    /// Check whether this refers to something with the given ID or slug.
+    ///
+    /// IDs are compared exactly, while slugs are compared case-insensitively like Modrinth does.
+    pub fn matches(&self, id: &Id, slug: Option<&str>) -> bool {
+        let this = self.as_ref();
+        this == id.as_ref() || slug.is_some_and(|slug| slug.eq_ignore_ascii_case(this))
+    }
+}
+
+impl<Id: AsRef<str>> AsRef<str> for IdOrSlug<Id> {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Id(id) => id.as_ref(),
+            Self::Slug(slug) => slug.as_ref(),
+        }
+    }
+}
+
+impl<Id: AsRef<str>> fmt::Display for IdOrSlug<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl<Id> FromStr for IdOrSlug<Id> {
+    type Err = ModrinthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::Slug(s.parse()?))
+    }
+}
+
+impl<Id> TryFrom<&str> for IdOrSlug<Id> {
+    type Error = ModrinthError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<Id> TryFrom<String> for IdOrSlug<Id> {
+    type Error = ModrinthError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Implement conversions into [IdOrSlug] for the IDs that can be used in place of a slug.
+macro_rules! id_or_slug {
+    ($($id:ident),*) => {$(
+        impl From<$id> for IdOrSlug<$id> {
+            fn from(id: $id) -> Self {
+                Self::Id(id)
+            }
+        }
+
+        impl From<&$id> for IdOrSlug<$id> {
+            fn from(id: &$id) -> Self {
+                Self::Id(id.clone())
+            }
+        }
+
+        impl From<Slug> for IdOrSlug<$id> {
+            fn from(slug: Slug) -> Self {
+                Self::Slug(slug)
+            }
+        }
+
+        impl From<&Slug> for IdOrSlug<$id> {
+            fn from(slug: &Slug) -> Self {
+                Self::Slug(slug.clone())
+            }
+        }
+    )*};
+}
+
+id_or_slug!(ProjectId, UserId);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base62_round_trips() {
+        for value in [0, 1, 61, 62, 3_521_614_606_208, u64::MAX] {
+            assert_eq!(decode(&encode(value)), Some(value));
+        }
+        assert_eq!(encode(62), "10");
+        assert_eq!(decode("zz"), Some(62 * 62 - 1));
+    }
+
+    #[test]
+    fn decode_rejects_invalid() {
+        assert_eq!(decode(""), None);
+        assert_eq!(decode("AAN-bbMI"), None);
+        // one more than u64::MAX
+        assert_eq!(decode("LygHa16AHYG"), None);
+    }
+
+    #[test]
+    fn ids_have_modrinth_shape() {
+        let id: ProjectId = "AANobbMI".parse().unwrap();
+        assert_eq!(ProjectId::try_from(id.value()).unwrap(), id);
+
+        assert!("sodium".parse::<ProjectId>().is_err());
+        assert!("AANobbMIx".parse::<ProjectId>().is_err());
+        assert!(ProjectId::try_from(61).is_err());
+    }
+
+    #[test]
+    fn ids_deserialize_from_any_base62() {
+        let short: ProjectId = serde_json::from_str(r#""Ab1""#).unwrap();
+        assert_eq!(short.value(), decode("Ab1").unwrap());
+        assert!(serde_json::from_str::<ProjectId>(r#""AANobbMIx""#).is_ok());
+
+        assert!(serde_json::from_str::<ProjectId>(r#""""#).is_err());
+        assert!(serde_json::from_str::<ProjectId>(r#""AAN-bbMI""#).is_err());
+    }
+
+    #[test]
+    fn slugs_are_validated() {
+        assert!("fabric-api".parse::<Slug>().is_ok());
+        assert!("".parse::<Slug>().is_err());
+        assert!("has space".parse::<Slug>().is_err());
+        assert!("a/b".parse::<Slug>().is_err());
+    }
+
+    #[test]
+    fn strings_parse_as_slugs() {
+        for s in ["sodium", "jellysquid", "AANobbMI"] {
+            let parsed: IdOrSlug = s.parse().unwrap();
+            assert!(matches!(parsed, IdOrSlug::Slug(_)));
+            assert_eq!(parsed.to_string(), s);
+        }
+
+        let id = IdOrSlug::<UserId>::id("TEZXhE2U").unwrap();
+        assert!(matches!(id, IdOrSlug::Id(_)));
+        assert!(IdOrSlug::<UserId>::id("jellysquid").is_err());
+        assert!(matches!(IdOrSlug::<UserId>::slug("jellysquid"), Ok(IdOrSlug::Slug(_))));
+    }
+
+    #[test]
+    fn matches_id_or_slug() {
+        let id: ProjectId = "AANobbMI".parse().unwrap();
+        let by_slug: IdOrSlug = "sodium".parse().unwrap();
+        let by_id: IdOrSlug = "AANobbMI".parse().unwrap();
+
+        assert!(by_slug.matches(&id, Some("sodium")));
+        assert!(by_id.matches(&id, Some("sodium")));
+        assert!(!by_slug.matches(&id, None));
+
+        let by_cased_slug: IdOrSlug = "Sodium".parse().unwrap();
+        let by_cased_id: IdOrSlug = "aanobbmi".parse().unwrap();
+        assert!(by_cased_slug.matches(&id, Some("sodium")));
+        assert!(!by_cased_id.matches(&id, None));
+    }
+}
@@ -0,0 +1,207 @@
+use crate::ModrinthError;
+use std::path::Path;
+
+/// The largest icon that Modrinth accepts, in bytes.
+#[cfg(any(feature = "projects", feature = "users"))]
+pub(crate) const ICON_LIMIT: usize = 256 * 1024;
+/// The largest gallery image that Modrinth accepts, in bytes.
+#[cfg(feature = "projects")]
+pub(crate) const GALLERY_LIMIT: usize = 5 * 1024 * 1024;
+
+/// An image format that Modrinth accepts for icons and gallery images.
+#[derive(
+    Debug, Clone, Copy, serde::Serialize, strum::Display, strum::EnumString, PartialEq, Eq,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum ImageFormat {
+    Png,
+    Jpg,
+    Jpeg,
+    Bmp,
+    Gif,
+    Webp,
+    Svg,
+    Svgz,
+    Rgb,
+}
+
+impl ImageFormat {
+    /// The content type of images in this format.
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpg | Self::Jpeg => "image/jpeg",
+            Self::Bmp => "image/bmp",
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+            Self::Svg | Self::Svgz => "image/svg+xml",
+            Self::Rgb => "image/x-rgb",
+        }
+    }
+}
+
+impl ImageFormat {
+    /// Infer the format of an image from its magic bytes.
+    ///
+    /// Compressed svgz images can't be told apart from any other gzip stream
+    /// without decompressing them, so they are not detected.
+    pub fn detect(image: &[u8]) -> Option<Self> {
+        match image {
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', ..] => Some(Self::Png),
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(Self::Gif),
+            [b'B', b'M', ..] if is_bmp(image) => Some(Self::Bmp),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::Webp),
+            // sgi magic, then the storage format and bytes per channel
+            [0x01, 0xDA, 0 | 1, 1 | 2, ..] => Some(Self::Rgb),
+            _ if is_svg(image) => Some(Self::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// Check the header of a file starting with `BM` to make sure it is a bitmap.
+fn is_bmp(image: &[u8]) -> bool {
+    let u32_at = |at: usize| {
+        image
+            .get(at..at + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+
+    // the reserved fields are zero, and the info header is one of the known sizes
+    u32_at(6) == Some(0)
+        && matches!(u32_at(14), Some(12 | 40 | 52 | 56 | 64 | 108 | 124))
+        && u32_at(10).is_some_and(|offset| (offset as usize) < image.len())
+}
+
+/// Check whether a file is an svg document, which is text with an `<svg>` root element.
+fn is_svg(image: &[u8]) -> bool {
+    let head = &image[..image.len().min(1024)];
+    let head = head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(head);
+
+    // the head may cut a character in half, so only the valid part is needed
+    let text = match std::str::from_utf8(head) {
+        Ok(text) => text,
+        Err(err) => std::str::from_utf8(&head[..err.valid_up_to()]).unwrap_or_default(),
+    };
+
+    text.trim_start().starts_with('<') && text.contains("<svg")
+}
+
+/// An image to upload to Modrinth, such as an icon, avatar or gallery image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    /// The contents of the image
+    pub bytes: Vec<u8>,
+    /// The format of the image
+    pub format: ImageFormat,
+}
+
+impl Image {
+    /// Create an image from its contents and format.
+    pub fn new(bytes: impl Into<Vec<u8>>, format: ImageFormat) -> Self {
+        Self {
+            bytes: bytes.into(),
+            format,
+        }
+    }
+
+    /// Create an image from its contents, inferring its format from its magic bytes.
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::UnknownImageFormat] if the format is not one Modrinth accepts.
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, ModrinthError> {
+        let bytes = bytes.into();
+        let format = ImageFormat::detect(&bytes).ok_or(ModrinthError::UnknownImageFormat)?;
+        Ok(Self { bytes, format })
+    }
+
+    /// Read an image from disk, inferring its format from its magic bytes.
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ModrinthError::Io] if the file could not be read,
+    /// or [ModrinthError::UnknownImageFormat] if the format is not one Modrinth accepts.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ModrinthError> {
+        Self::from_bytes(std::fs::read(path)?)
+    }
+
+    /// Get the contents of the image, making sure it is small enough to be accepted by Modrinth.
+    #[cfg(any(feature = "projects", feature = "users"))]
+    pub(crate) fn checked(&self, name: &'static str, max: usize) -> Result<Vec<u8>, ModrinthError> {
+        if self.bytes.len() > max {
+            return Err(ModrinthError::OutOfRange {
+                name,
+                value: self.bytes.len(),
+                min: 0,
+                max,
+            });
+        }
+        Ok(self.bytes.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal bitmap header, with pixel data starting right after it.
+    fn bmp() -> Vec<u8> {
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&58u32.to_le_bytes());
+        bmp.extend_from_slice(&0u32.to_le_bytes());
+        bmp.extend_from_slice(&54u32.to_le_bytes());
+        bmp.extend_from_slice(&40u32.to_le_bytes());
+        bmp.resize(58, 0);
+        bmp
+    }
+
+    #[test]
+    fn detects_formats() {
+        let cases: &[(&[u8], Option<ImageFormat>)] = &[
+            (b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR", Some(ImageFormat::Png)),
+            (b"\xFF\xD8\xFF\xE0\0\x10JFIF", Some(ImageFormat::Jpeg)),
+            (b"GIF87a\x01\0\x01\0", Some(ImageFormat::Gif)),
+            (b"GIF89a\x01\0\x01\0", Some(ImageFormat::Gif)),
+            (&bmp(), Some(ImageFormat::Bmp)),
+            (b"RIFF\x24\0\0\0WEBPVP8 ", Some(ImageFormat::Webp)),
+            (b"\x01\xDA\x01\x02\0\x03", Some(ImageFormat::Rgb)),
+            (b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>", Some(ImageFormat::Svg)),
+            (
+                b"\xEF\xBB\xBF<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\"/>",
+                Some(ImageFormat::Svg),
+            ),
+            // a text file that happens to start with the bitmap signature
+            (b"BM is the bitmap signature, but this is text.", None),
+            // gzip, which svgz can't be told apart from
+            (b"\x1F\x8B\x08\0\0\0\0\0", None),
+            (b"<html><body></body></html>", None),
+            (b"RIFF\x24\0\0\0WAVEfmt ", None),
+        ];
+
+        for (bytes, format) in cases {
+            assert_eq!(ImageFormat::detect(bytes), *format, "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn short_inputs_are_not_detected() {
+        let signatures: &[&[u8]] = &[
+            b"\x89PNG\r\n\x1A\n",
+            b"\xFF\xD8\xFF",
+            b"GIF89a",
+            &bmp(),
+            b"RIFF\x24\0\0\0WEBP",
+            b"\x01\xDA\x01\x02",
+        ];
+
+        for signature in signatures {
+            // a bitmap is only recognized once its info header size is complete
+            for len in 0..signature.len().min(18) {
+                assert_eq!(ImageFormat::detect(&signature[..len]), None, "{signature:?}[..{len}]");
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::ModrinthError;
+use rustify::errors::ClientError;
+
+/// Serialize a raw JSON request body.
+pub(crate) fn json_body(value: &impl serde::Serialize) -> Result<Vec<u8>, ModrinthError> {
+    serde_json::to_vec(value)
+        .map_err(|source| ClientError::DataParseError {
+            source: source.into(),
+        })
+        .map_err(Into::into)
+}
@@ -0,0 +1,249 @@
+use std::{io, path::Path};
+#[cfg(any(feature = "projects", feature = "versions"))]
+use {
+    super::ImageFormat,
+    crate::ModrinthError,
+    std::hash::{BuildHasher, RandomState},
+};
+
+/// The content type of a [Multipart] body.
+///
+/// The boundary is only known once the body is finished,
+/// so the [AuthMiddleware](crate::client::AuthMiddleware) completes it from the body.
+#[cfg(any(feature = "projects", feature = "versions"))]
+pub(crate) const MULTIPART: &str = "multipart/form-data";
+
+/// The boundary tried first to separate the parts of a [Multipart] body.
+#[cfg(any(feature = "projects", feature = "versions"))]
+const BOUNDARY: &str = "amerinth-9f3c27d5e18b4a60";
+
+/// A file to upload to Modrinth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadFile {
+    /// The name of the file, including its extension
+    pub name: String,
+    /// The contents of the file
+    pub bytes: Vec<u8>,
+}
+
+impl UploadFile {
+    /// Create a file from its name and contents.
+    pub fn new(name: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        Self {
+            name: name.into(),
+            bytes: bytes.into(),
+        }
+    }
+
+    /// Read a file from disk, using its file name as the name of the upload.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid file name"))?;
+
+        Ok(Self::new(name, std::fs::read(path)?))
+    }
+
+    /// The content type of the file, based on its extension.
+    #[cfg(any(feature = "projects", feature = "versions"))]
+    fn content_type(&self) -> &'static str {
+        let ext = self.name.rsplit_once('.').map(|(_, ext)| ext);
+        if let Some(format) = ext.and_then(|ext| ext.parse::<ImageFormat>().ok()) {
+            return format.content_type();
+        }
+
+        match ext.map(str::to_lowercase).as_deref() {
+            Some("jar") => "application/java-archive",
+            Some("zip") => "application/zip",
+            Some("mrpack") => "application/x-modrinth-modpack+zip",
+            _ => "application/octet-stream",
+        }
+    }
+}
+
+/// A `multipart/form-data` request body.
+#[cfg(any(feature = "projects", feature = "versions"))]
+pub(crate) struct Multipart {
+    parts: Vec<Part>,
+}
+
+/// A part of a [Multipart] body.
+#[cfg(any(feature = "projects", feature = "versions"))]
+struct Part {
+    headers: String,
+    bytes: Vec<u8>,
+    file: bool,
+}
+
+/// A piece of a [Multipart] body to stream.
+#[cfg(feature = "versions")]
+pub(crate) struct Chunk {
+    pub(crate) bytes: Vec<u8>,
+    /// The index of the file this chunk is from, and how much of it has been sent once it is
+    pub(crate) file: Option<(usize, usize)>,
+}
+
+#[cfg(any(feature = "projects", feature = "versions"))]
+impl Multipart {
+    pub(crate) fn new() -> Self {
+        Self { parts: Vec::new() }
+    }
+
+    /// Append a part with the given headers and contents.
+    fn part(mut self, disposition: &str, content_type: &str, bytes: Vec<u8>, file: bool) -> Self {
+        self.parts.push(Part {
+            headers: format!(
+                "Content-Disposition: form-data; {disposition}\r\nContent-Type: {content_type}\r\n\r\n"
+            ),
+            bytes,
+            file,
+        });
+        self
+    }
+
+    /// Append a JSON part.
+    pub(crate) fn json(self, name: &str, value: &impl serde::Serialize) -> Result<Self, ModrinthError> {
+        let bytes = super::json_body(value)?;
+        Ok(self.part(&format!(r#"name="{name}""#), "application/json", bytes, false))
+    }
+
+    /// Append a file part.
+    pub(crate) fn file(self, name: &str, file: &UploadFile) -> Self {
+        // keep the file name from breaking out of its quotes or header line
+        let filename: String = file
+            .name
+            .chars()
+            .filter(|c| !c.is_control() && *c != '"')
+            .collect();
+
+        self.part(
+            &format!(r#"name="{name}"; filename="{filename}""#),
+            file.content_type(),
+            file.bytes.clone(),
+            true,
+        )
+    }
+
+    /// Pick a boundary that does not appear in any of the parts.
+    fn boundary(&self) -> String {
+        let unused = |boundary: &str| {
+            self.parts.iter().all(|part| {
+                !part.headers.contains(boundary)
+                    && !part
+                        .bytes
+                        .windows(boundary.len())
+                        .any(|window| window == boundary.as_bytes())
+            })
+        };
+
+        let mut boundary = BOUNDARY.to_string();
+        let mut attempt = 0u64;
+        while !unused(&boundary) {
+            attempt += 1;
+            boundary = format!("amerinth-{:016x}", RandomState::new().hash_one(attempt));
+        }
+        boundary
+    }
+
+    /// Finish the body.
+    #[cfg(any(feature = "projects", test))]
+    pub(crate) fn finish(self) -> Vec<u8> {
+        let boundary = self.boundary();
+        let mut body = Vec::new();
+        for part in self.parts {
+            body.extend_from_slice(format!("--{boundary}\r\n{}", part.headers).as_bytes());
+            body.extend_from_slice(&part.bytes);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        body
+    }
+
+    /// Finish the body as chunks of at most `size` bytes of file contents, along with its boundary.
+    #[cfg(feature = "versions")]
+    pub(crate) fn chunks(self, size: usize) -> (String, Vec<Chunk>) {
+        let boundary = self.boundary();
+        let mut chunks = Vec::new();
+        let mut files = 0;
+        for part in self.parts {
+            let head = format!("--{boundary}\r\n{}", part.headers).into_bytes();
+            if part.file {
+                chunks.push(Chunk { bytes: head, file: None });
+                let mut sent = 0;
+                // empty files still get a chunk, so their progress is reported
+                let empty = part.bytes.is_empty().then_some(&[][..]);
+                for bytes in part.bytes.chunks(size).chain(empty) {
+                    sent += bytes.len();
+                    chunks.push(Chunk {
+                        bytes: bytes.to_vec(),
+                        file: Some((files, sent)),
+                    });
+                }
+                files += 1;
+            } else {
+                chunks.push(Chunk {
+                    bytes: [head, part.bytes].concat(),
+                    file: None,
+                });
+            }
+            chunks.push(Chunk { bytes: b"\r\n".to_vec(), file: None });
+        }
+        chunks.push(Chunk {
+            bytes: format!("--{boundary}--\r\n").into_bytes(),
+            file: None,
+        });
+        (boundary, chunks)
+    }
+}
+
+/// Get the boundary of a finished [Multipart] body, which it starts with.
+#[cfg(any(feature = "projects", feature = "versions"))]
+pub(crate) fn boundary_of(body: &[u8]) -> Option<&str> {
+    let line = body.strip_prefix(b"--")?.split(|&b| b == b'\r').next()?;
+    std::str::from_utf8(line).ok()
+}
+
+#[cfg(all(test, any(feature = "projects", feature = "versions")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boundary_avoids_contents() {
+        let file = UploadFile::new("a.jar", format!("x--{BOUNDARY}x"));
+        let body = Multipart::new().file("file", &file).finish();
+
+        let boundary = boundary_of(&body).unwrap();
+        assert_ne!(boundary, BOUNDARY);
+        assert!(body.ends_with(format!("--{boundary}--\r\n").as_bytes()));
+    }
+
+    #[test]
+    fn file_names_are_sanitized() {
+        let file = UploadFile::new("a\"\r\nX-Injected: 1.jar", "");
+        let body = Multipart::new().file("file", &file).finish();
+        let body = String::from_utf8(body).unwrap();
+
+        assert!(body.contains(r#"filename="aX-Injected: 1.jar""#));
+        assert!(!body.contains("\r\nX-Injected"));
+    }
+
+    #[test]
+    #[cfg(feature = "versions")]
+    fn chunks_match_finished_body() {
+        let files = [UploadFile::new("a.jar", vec![1; 5]), UploadFile::new("b.jar", "")];
+        let form = || {
+            let form = Multipart::new().json("data", &"x").unwrap();
+            files.iter().fold(form, |form, file| form.file(&file.name, file))
+        };
+
+        let (boundary, chunks) = form().chunks(2);
+        let body = form().finish();
+        assert_eq!(boundary_of(&body), Some(boundary.as_str()));
+        assert_eq!(chunks.iter().flat_map(|chunk| chunk.bytes.clone()).collect::<Vec<_>>(), body);
+
+        let progress: Vec<_> = chunks.iter().filter_map(|chunk| chunk.file).collect();
+        assert_eq!(progress, [(0, 2), (0, 4), (0, 5), (1, 0)]);
+    }
+}
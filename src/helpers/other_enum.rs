@@ -12,14 +12,14 @@ macro_rules! other_enum {
         $(#[$other_meta:meta])*
         $other:ident($other_ty:ty)
     ) => {
-        #[derive(Debug, Clone, strum::EnumString)]
+        #[derive(Debug, Clone, strum::Display, strum::EnumString)]
         $(#[$enum_meta])*
         $vis enum $enum {
             $(
                 $(#[$var_meta])*
                 $variant
             ),*,
-            #[strum(disabled)]
+            #[strum(default)]
             $(#[$other_meta])*
             $other($other_ty)
         }
@@ -33,6 +33,15 @@ macro_rules! other_enum {
                 Ok(v.parse().unwrap_or(Self::$other(v)))
             }
         }
+
+        impl serde::Serialize for $enum {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
     };
 }
 pub(crate) use other_enum;
@@ -0,0 +1,254 @@
+use super::{MULTIPART, Multipart, UploadFile};
+use crate::client::{AuthMiddleware, AuthState, Modrinth};
+use http::HeaderValue;
+use rustify::{Endpoint, client::HTTP_SUCCESS_CODES, errors::ClientError};
+use serde::de::DeserializeOwned;
+
+/// The most file contents sent at once, and so the granularity of [FileProgress].
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The progress of a file being uploaded.
+///
+/// Progress is reported as the file's contents are handed to the HTTP client,
+/// so every file reaches `sent == file.bytes.len()` once it has been sent.
+#[derive(Debug, Clone, Copy)]
+pub struct FileProgress<'a> {
+    /// The file being uploaded
+    pub file: &'a UploadFile,
+    /// The index of the file among the uploaded files
+    pub index: usize,
+    /// The number of files being uploaded
+    pub count: usize,
+    /// The number of bytes of this file sent so far
+    pub sent: usize,
+    /// The number of bytes of all files sent so far
+    pub uploaded: usize,
+    /// The total number of bytes of all files
+    pub total: usize,
+}
+
+/// Turns the file chunks sent by the HTTP client into [FileProgress].
+struct Tracker<'a, F> {
+    files: &'a [UploadFile],
+    uploaded: usize,
+    total: usize,
+    progress: F,
+}
+
+impl<'a, F: FnMut(FileProgress<'a>)> Tracker<'a, F> {
+    fn new(files: &'a [UploadFile], progress: F) -> Self {
+        Self {
+            files,
+            uploaded: 0,
+            total: files.iter().map(|file| file.bytes.len()).sum(),
+            progress,
+        }
+    }
+
+    /// Record that `len` bytes of a file were sent, bringing it to `sent` bytes.
+    fn sent(&mut self, (index, sent): (usize, usize), len: usize) {
+        self.uploaded += len;
+        (self.progress)(FileProgress {
+            file: &self.files[index],
+            index,
+            count: self.files.len(),
+            sent,
+            uploaded: self.uploaded,
+            total: self.total,
+        });
+    }
+}
+
+/// Build the request for an upload, whose body is streamed separately.
+fn request<Auth: AuthState>(
+    modrinth: &Modrinth<Auth>,
+    endpoint: impl Endpoint,
+    boundary: &str,
+) -> Result<http::Request<Vec<u8>>, ClientError> {
+    let middleware = AuthMiddleware(modrinth, None);
+    let mut req = endpoint
+        .with_middleware(&middleware)
+        .request(&modrinth.client.base)?;
+
+    // the middleware only sets a content type for the body it can see
+    let content_type = HeaderValue::from_str(&format!("{MULTIPART}; boundary={boundary}"))
+        .map_err(|source| ClientError::GenericError {
+            source: source.into(),
+        })?;
+    req.headers_mut().insert("Content-Type", content_type);
+    Ok(req)
+}
+
+/// Check the status of an upload's response, returning its body if it succeeded.
+fn response(code: u16, body: Vec<u8>) -> Result<Vec<u8>, ClientError> {
+    match HTTP_SUCCESS_CODES.contains(&code) {
+        true => Ok(body),
+        false => Err(ClientError::ServerResponseError {
+            code,
+            content: String::from_utf8(body).ok(),
+        }),
+    }
+}
+
+/// Parse the JSON body of an upload's response.
+pub(crate) fn parse<T: DeserializeOwned>(body: Vec<u8>) -> Result<T, ClientError> {
+    serde_json::from_slice(&body).map_err(|source| ClientError::ResponseParseError {
+        source: source.into(),
+        content: String::from_utf8(body).ok(),
+    })
+}
+
+/// Send a [Multipart] body to an endpoint, reporting the progress of each of its files.
+///
+/// `files` must be the files of the body, in the order they were added.
+#[cfg(not(feature = "blocking"))]
+pub(crate) async fn upload<'a, Auth: AuthState>(
+    modrinth: &Modrinth<Auth>,
+    endpoint: impl Endpoint,
+    form: Multipart,
+    files: &'a [UploadFile],
+    progress: impl FnMut(FileProgress<'a>),
+) -> Result<Vec<u8>, ClientError> {
+    use futures::{StreamExt, channel::mpsc};
+
+    let (boundary, chunks) = form.chunks(CHUNK_SIZE);
+    let req = request(modrinth, endpoint, &boundary)?;
+    let mut req =
+        reqwest::Request::try_from(req).map_err(|source| ClientError::ReqwestBuildError { source })?;
+    let url = req.url().to_string();
+    let method = req.method().to_string();
+
+    // report each chunk of a file as the client pulls it from the stream
+    let (tx, mut rx) = mpsc::unbounded();
+    let body = futures::stream::iter(chunks).map(move |chunk| {
+        if let Some(file) = chunk.file {
+            // the receiver only goes away once the request is done
+            let _ = tx.unbounded_send((file, chunk.bytes.len()));
+        }
+        Ok::<_, std::io::Error>(chunk.bytes)
+    });
+    *req.body_mut() = Some(reqwest::Body::wrap_stream(body));
+
+    let send = async {
+        let res = modrinth
+            .client
+            .http
+            .execute(req)
+            .await
+            .map_err(|source| ClientError::RequestError {
+                source: source.into(),
+                url,
+                method,
+            })?;
+        let code = res.status().as_u16();
+        let body = res
+            .bytes()
+            .await
+            .map_err(|source| ClientError::ResponseError {
+                source: source.into(),
+            })?;
+        response(code, body.to_vec())
+    };
+
+    let mut tracker = Tracker::new(files, progress);
+    let track = async {
+        while let Some((file, len)) = rx.next().await {
+            tracker.sent(file, len);
+        }
+    };
+
+    futures::join!(send, track).0
+}
+
+/// Send a [Multipart] body to an endpoint, reporting the progress of each of its files.
+///
+/// `files` must be the files of the body, in the order they were added.
+#[cfg(feature = "blocking")]
+pub(crate) fn upload<'a, Auth: AuthState>(
+    modrinth: &Modrinth<Auth>,
+    endpoint: impl Endpoint,
+    form: Multipart,
+    files: &'a [UploadFile],
+    progress: impl FnMut(FileProgress<'a>),
+) -> Result<Vec<u8>, ClientError> {
+    use std::sync::mpsc;
+
+    let (boundary, chunks) = form.chunks(CHUNK_SIZE);
+    let len = chunks.iter().map(|chunk| chunk.bytes.len() as u64).sum();
+    let req = request(modrinth, endpoint, &boundary)?;
+    let mut req = reqwest::blocking::Request::try_from(req)
+        .map_err(|source| ClientError::ReqwestBuildError { source })?;
+    let url = req.url().to_string();
+    let method = req.method().to_string();
+
+    let (tx, rx) = mpsc::channel();
+    let reader = ChunkReader {
+        chunks: chunks.into(),
+        offset: 0,
+        tx,
+    };
+    *req.body_mut() = Some(reqwest::blocking::Body::sized(reader, len));
+
+    let mut tracker = Tracker::new(files, progress);
+    std::thread::scope(|scope| {
+        // the client reads the body on this thread, while progress is reported on the caller's
+        let send = scope.spawn(|| {
+            let res = modrinth
+                .client
+                .http
+                .execute(req)
+                .map_err(|source| ClientError::RequestError {
+                    source: source.into(),
+                    url,
+                    method,
+                })?;
+            let code = res.status().as_u16();
+            let body = res.bytes().map_err(|source| ClientError::ResponseError {
+                source: source.into(),
+            })?;
+            response(code, body.to_vec())
+        });
+
+        for (file, len) in rx {
+            tracker.sent(file, len);
+        }
+
+        send.join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    })
+}
+
+/// Reads the chunks of a [Multipart] body, reporting each chunk of a file once it is read.
+#[cfg(feature = "blocking")]
+struct ChunkReader {
+    chunks: std::collections::VecDeque<super::Chunk>,
+    offset: usize,
+    tx: std::sync::mpsc::Sender<((usize, usize), usize)>,
+}
+
+#[cfg(feature = "blocking")]
+impl std::io::Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while let Some(chunk) = self.chunks.front() {
+            let read = (chunk.bytes.len() - self.offset).min(buf.len());
+            buf[..read].copy_from_slice(&chunk.bytes[self.offset..self.offset + read]);
+            self.offset += read;
+
+            if self.offset == chunk.bytes.len() {
+                if let Some(file) = chunk.file {
+                    // the receiver only goes away once the request is done
+                    let _ = self.tx.send((file, chunk.bytes.len()));
+                }
+                self.chunks.pop_front();
+                self.offset = 0;
+            }
+
+            // empty chunks are skipped, as reading nothing would end the body
+            if read > 0 || buf.is_empty() {
+                return Ok(read);
+            }
+        }
+        Ok(0)
+    }
+}
+
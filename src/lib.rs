@@ -6,6 +6,14 @@ mod client;
 pub use client::{Modrinth, UserAgent};
 
 mod helpers;
+#[cfg(feature = "hashing")]
+pub use helpers::FileHashes;
+#[cfg(feature = "versions")]
+pub use helpers::FileProgress;
+pub use helpers::{
+    IdOrSlug, Image, ImageFormat, NotificationId, ProjectId, ReportId, Slug, TeamId, ThreadId,
+    UploadFile, UserId, VersionId,
+};
 
 /// An error that can occur when using the Modrinth API.
 #[derive(Debug, thiserror::Error)]
@@ -14,14 +22,59 @@ pub enum ModrinthError {
     #[error(r#"Resource not found: {resource} "{id}""#)]
     NotFound { resource: &'static str, id: String },
 
+    /// A string is not a valid base62 ID.
+    #[error(r#"Invalid {kind} ID: "{id}""#)]
+    InvalidId { kind: &'static str, id: String },
+
+    /// A string is not a valid slug.
+    #[error(r#"Invalid slug: "{0}""#)]
+    InvalidSlug(String),
+
     /// Client is unauthorized.
     #[error(r#"Unauthorized"#)]
     Unauthorized,
 
+    /// The project is already being followed.
+    #[error(r#"Already following project "{0}""#)]
+    AlreadyFollowing(String),
+
+    /// The project is not being followed.
+    #[error(r#"Not following project "{0}""#)]
+    NotFollowing(String),
+
+    /// A value is outside of the range accepted by Modrinth.
+    #[error(r#"Value out of range: {name} must be between {min} and {max}, got {value}"#)]
+    OutOfRange {
+        name: &'static str,
+        value: usize,
+        min: usize,
+        max: usize,
+    },
+
+    /// The username is already taken by another user.
+    #[error(r#"Username is taken: "{0}""#)]
+    UsernameTaken(String),
+
+    /// Modrinth rejected the request as invalid.
+    #[error(r#"Invalid input: {0}"#)]
+    InvalidInput(String),
+
+    /// A release was scheduled for a time that is not in the future.
+    #[error(r#"Scheduled time is not in the future: {0}"#)]
+    Schedule(chrono::DateTime<chrono::Utc>),
+
     /// Invalid expiration date.
     #[error(r#"Invalid expiration date: {0}/{1}/{2}"#)]
     Expiration(u8, u8, i32),
 
+    /// An image is not in a format that Modrinth accepts.
+    #[error(r#"Unknown image format"#)]
+    UnknownImageFormat,
+
+    /// A file could not be read.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("Client error: {0}")]
     Client(#[from] rustify::errors::ClientError),
 
@@ -38,6 +91,6 @@ pub(crate) mod prelude {
     pub(crate) use chrono::{DateTime, Utc};
     pub(crate) use rustify::Endpoint;
     pub(crate) use rustify::errors::ClientError;
-    pub(crate) use serde::Deserialize;
+    pub(crate) use serde::{Deserialize, Serialize};
     pub(crate) use strum::EnumString;
 }